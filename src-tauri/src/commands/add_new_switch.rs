@@ -1,5 +1,10 @@
-use crate::models::{NewSwitchSubmission, SoundEffect, SwitchData, SwitchType};
+use crate::models::{
+    NewSwitchSubmission, ReviewStatus, SwitchData, SwitchTypeDefaultsProfile, SwitchTypeDefinition,
+};
+use crate::switch_defaults;
+use crate::switch_types;
 use crate::utils;
+use crate::validation;
 use serde_json::Value as Json;
 
 #[tauri::command]
@@ -12,29 +17,22 @@ pub async fn add_new_switch(
     // Get current config file info
     let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
 
-    // Check for nonSwitchRawNodeNames first
-    if !json_data
-        .as_object()
-        .unwrap()
-        .contains_key("nonSwitchRawNodeNames")
-    {
-        let _ = crate::commands::load_plane_model_data::load_plane_model_data(app_handle.clone())
-            .await?;
-        json_data = utils::load_json_file(&file_path)?;
-    }
+    // Omitted fields are filled from the workspace defaults for each switch type
+    let profile = switch_defaults::load_profile(&app_handle)?;
+    let registry = switch_types::load_registry(&app_handle)?;
 
-    // Now get the switches section after potential update
+    // Ensure switches section exists
     let switches = utils::ensure_section_exists(&mut json_data, "switches")?;
 
     // Process the form data
     if form_data.is_array() {
         let submissions: Vec<NewSwitchSubmission> = utils::deserialize_json(form_data)?;
         for submission in submissions {
-            process_single_switch(&submission, switches)?;
+            process_single_switch(&submission, &profile, &registry, switches)?;
         }
     } else {
         let submission: NewSwitchSubmission = utils::deserialize_json(form_data)?;
-        process_single_switch(&submission, switches)?;
+        process_single_switch(&submission, &profile, &registry, switches)?;
     }
 
     // Save the updated JSON
@@ -45,31 +43,48 @@ pub async fn add_new_switch(
 
 fn process_single_switch(
     submission: &NewSwitchSubmission,
+    profile: &SwitchTypeDefaultsProfile,
+    registry: &[SwitchTypeDefinition],
     switches: &mut serde_json::Value,
 ) -> Result<(), String> {
-    // Determine the appropriate sound effect based on switch type.
-    let sound_effect = match submission.switch_type.clone() {
-        SwitchType::Lever => SoundEffect::LeverSound,
-        SwitchType::Button => SoundEffect::ButtonSound,
-        SwitchType::Dial => SoundEffect::DialSound,
-        SwitchType::Throttle => SoundEffect::ThrottleSound,
-    };
+    // The type defaults decide the sound effect and any field the submission left out.
+    let defaults = switch_defaults::defaults_for(profile, &submission.switch_type);
 
     let switch_data = SwitchData {
         // Clone the non-Copy fields to avoid moving out of the submission.
         switch_type: submission.switch_type.clone(),
         switch_description: submission.switch_description.clone(),
-        movement_axis: submission.movement_axis.clone(),
-        movement_mode: submission.movement_mode,
-        momentary_switch: submission.momentary_switch,
-        bleed_margins: submission.bleed_margins,
-        default_position: submission.default_position,
-        upper_limit: submission.upper_limit,
-        lower_limit: submission.lower_limit,
-        sound_effect,
+        movement_axis: submission
+            .movement_axis
+            .clone()
+            .unwrap_or(defaults.movement_axis),
+        motion: submission.motion.unwrap_or(defaults.motion),
+        units: submission.units.unwrap_or(defaults.units),
+        discrete: submission.discrete.unwrap_or(defaults.discrete),
+        momentary_switch: submission
+            .momentary_switch
+            .unwrap_or(defaults.momentary_switch),
+        bleed_margins: submission.bleed_margins.unwrap_or(defaults.bleed_margins),
+        default_position: submission
+            .default_position
+            .unwrap_or(defaults.default_position),
+        upper_limit: submission.upper_limit.unwrap_or(defaults.upper_limit),
+        lower_limit: submission.lower_limit.unwrap_or(defaults.lower_limit),
+        sound_effect: defaults.sound_effect,
         raw_node_name: submission.raw_node_name.clone(),
+        visual_node_name: submission.visual_node_name.clone(),
+        collider_node_name: submission.collider_node_name.clone(),
+        review_status: ReviewStatus::Reviewed,
+        positions: submission.positions.clone(),
+        additional_axes: submission.additional_axes.clone(),
+        axis_couplings: submission.axis_couplings.clone(),
+        behavior: submission.behavior.clone(),
+        sound_overrides: submission.sound_overrides.clone(),
     };
 
+    // Refuse to save a switch that fails validation
+    validation::ensure_valid_switch(&submission.switch_name, &switch_data, registry)?;
+
     // Serialize and insert the new switch data
     let new_switch_value = utils::serialize_to_json(&switch_data)?;
 
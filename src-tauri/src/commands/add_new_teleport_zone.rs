@@ -1,36 +1,36 @@
-use serde_json::Value as Json;
+use crate::geometry;
 use crate::models::NewTeleportZoneSubmission;
 use crate::utils;
+use serde_json::Value as Json;
 
 #[tauri::command]
 pub fn add_new_teleport_zone(
     app_handle: tauri::AppHandle,
     form_data: Json,
 ) -> Result<String, String> {
-    use serde_json::json;
-
     println!("Adding teleport zone: {:?}", &form_data);
 
     // Get current config file info
     let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
 
-    // Ensure teleportZones section exists
-    let teleport_zones = utils::ensure_section_exists(&mut json_data, "teleportZones")?;
-
     // Deserialize submission (only one zone at a time)
     let submission: NewTeleportZoneSubmission = utils::deserialize_json(form_data)?;
 
+    // Anything left out gets its default, the model is only needed for snapping
+    let mut new_zone = submission.to_zone();
+    if submission.snap_to_floor {
+        geometry::snap_to_floor(utils::get_model_path(&json_data)?, &mut new_zone)?;
+    }
+
+    // Ensure teleportZones section exists
+    let teleport_zones = utils::ensure_section_exists(&mut json_data, "teleportZones")?;
+
     // Insert or update the teleport zone by name
-    let new_zone = json!({
-        "x": submission.x,
-        "y": submission.y,
-        "z": submission.z,
-    });
-    
-    teleport_zones
-        .as_object_mut()
-        .unwrap()
-        .insert(submission.teleport_zone_name, new_zone);
+
+    teleport_zones.as_object_mut().unwrap().insert(
+        submission.teleport_zone_name,
+        utils::serialize_to_json(&new_zone)?,
+    );
 
     // Save the updated JSON
     utils::save_json_file(&file_path, &json_data)?;
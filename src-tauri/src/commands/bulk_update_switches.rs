@@ -0,0 +1,90 @@
+use crate::models::{SwitchData, SwitchPatch, SwitchUpdateOutcome, SwitchUpdateResult};
+use crate::switch_defaults;
+use crate::switch_patch;
+use crate::switch_types;
+use crate::utils;
+use crate::validation;
+
+/// Apply one partial update to many switches. Fields the patch leaves unset keep each switch's
+/// own value, and every switch gets its own result so one bad entry doesn't block the rest.
+#[tauri::command]
+pub fn bulk_update_switches(
+    app_handle: tauri::AppHandle,
+    switch_keys: Vec<String>,
+    patch: SwitchPatch,
+) -> Result<Vec<SwitchUpdateResult>, String> {
+    println!(
+        "Bulk updating {} switch(es): {:?}",
+        switch_keys.len(),
+        &patch
+    );
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let registry = switch_types::load_registry(&app_handle)?;
+    let profile = switch_defaults::load_profile(&app_handle)?;
+
+    // Ensure switches section exists
+    let switches = utils::ensure_section_exists(&mut json_data, "switches")?;
+    let switches_obj = switches.as_object_mut().unwrap();
+
+    let mut results = Vec::with_capacity(switch_keys.len());
+
+    for switch_key in switch_keys {
+        let Some(entry) = switches_obj.get_mut(&switch_key) else {
+            results.push(SwitchUpdateResult {
+                switch_key,
+                outcome: SwitchUpdateOutcome::NotFound,
+                changes: vec![],
+                error: None,
+            });
+            continue;
+        };
+
+        let before: SwitchData = match utils::deserialize_json(entry.clone()) {
+            Ok(before) => before,
+            Err(e) => {
+                results.push(SwitchUpdateResult {
+                    switch_key,
+                    outcome: SwitchUpdateOutcome::Rejected,
+                    changes: vec![],
+                    error: Some(e),
+                });
+                continue;
+            }
+        };
+        let mut after = before.clone();
+        switch_patch::apply_patch(&mut after, &patch, &profile);
+
+        if let Err(e) = validation::ensure_valid_switch(&switch_key, &after, &registry) {
+            results.push(SwitchUpdateResult {
+                switch_key,
+                outcome: SwitchUpdateOutcome::Rejected,
+                changes: vec![],
+                error: Some(e),
+            });
+            continue;
+        }
+
+        let changes = switch_patch::diff_switch(&before, &after)?;
+        let outcome = if changes.is_empty() {
+            SwitchUpdateOutcome::Unchanged
+        } else {
+            *entry = utils::serialize_to_json(&after)?;
+            SwitchUpdateOutcome::Updated
+        };
+
+        results.push(SwitchUpdateResult {
+            switch_key,
+            outcome,
+            changes,
+            error: None,
+        });
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(results)
+}
@@ -0,0 +1,193 @@
+use crate::models::{CompositeControl, SwitchData, ValidationSeverity};
+use crate::utils;
+use crate::validation;
+use std::collections::{HashMap, HashSet};
+
+const COMPOSITES_SECTION: &str = "compositeControls";
+const DEFAULT_COVER_SUFFIX: &str = "Cover";
+
+#[tauri::command]
+pub fn set_composite_control(
+    app_handle: tauri::AppHandle,
+    composite_name: String,
+    composite: CompositeControl,
+) -> Result<String, String> {
+    println!(
+        "Setting composite control '{}': {:?}",
+        &composite_name, &composite
+    );
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+    let composites: HashMap<String, CompositeControl> =
+        utils::read_section(&json_data, COMPOSITES_SECTION)?;
+
+    let mut errors: Vec<String> =
+        validation::validate_composite(&composite_name, &composite, &switches)
+            .into_iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+            .map(|issue| issue.message)
+            .collect();
+
+    // A switch can only be a part of one composite control
+    let mut owners: Vec<(&String, &CompositeControl)> = composites
+        .iter()
+        .filter(|(name, _)| **name != composite_name)
+        .collect();
+    owners.sort_by_key(|(name, _)| *name);
+    for part in [&composite.cover_switch, &composite.guarded_switch] {
+        if let Some((owner, _)) = owners
+            .iter()
+            .find(|(_, other)| [&other.cover_switch, &other.guarded_switch].contains(&part))
+        {
+            errors.push(format!("switch '{}' is already part of '{}'", part, owner));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(format!(
+            "Composite control '{}' is invalid: {}",
+            composite_name,
+            errors.join("; ")
+        ));
+    }
+
+    // Ensure compositeControls section exists
+    let composites = utils::ensure_section_exists(&mut json_data, COMPOSITES_SECTION)?;
+
+    composites.as_object_mut().unwrap().insert(
+        composite_name.clone(),
+        utils::serialize_to_json(&composite)?,
+    );
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!(
+        "Composite control '{}' saved successfully",
+        composite_name
+    ))
+}
+
+#[tauri::command]
+pub fn remove_composite_control(
+    app_handle: tauri::AppHandle,
+    composite_name: String,
+) -> Result<String, String> {
+    println!("Removing composite control: {}", composite_name);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    // Ensure compositeControls section exists
+    let composites = utils::ensure_section_exists(&mut json_data, COMPOSITES_SECTION)?;
+
+    if composites
+        .as_object_mut()
+        .unwrap()
+        .remove(&composite_name)
+        .is_none()
+    {
+        return Err(format!("Composite control '{}' not found", composite_name));
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!(
+        "Composite control '{}' removed successfully",
+        composite_name
+    ))
+}
+
+/// Link every switch named `<name><suffix>` (e.g. "Fuel Cutoff Cover") to the switch `<name>`
+/// as its guard cover. Switches already in a composite are left alone. Returns what was added.
+#[tauri::command]
+pub fn detect_composite_controls(
+    app_handle: tauri::AppHandle,
+    cover_suffix: Option<String>,
+) -> Result<HashMap<String, CompositeControl>, String> {
+    let cover_suffix = cover_suffix.unwrap_or_else(|| DEFAULT_COVER_SUFFIX.to_string());
+    if cover_suffix.trim().is_empty() {
+        return Err("Cover suffix must not be empty".to_string());
+    }
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+    let mut composites: HashMap<String, CompositeControl> =
+        utils::read_section(&json_data, COMPOSITES_SECTION)?;
+
+    let mut taken: HashSet<String> = composites
+        .values()
+        .flat_map(|c| [c.cover_switch.clone(), c.guarded_switch.clone()])
+        .collect();
+
+    let mut cover_keys: Vec<&String> = switches.keys().collect();
+    cover_keys.sort();
+
+    let mut detected = HashMap::new();
+    for cover_key in cover_keys {
+        let Some(base) = strip_suffix_ignore_case(cover_key, &cover_suffix) else {
+            continue;
+        };
+        let base = base.trim_end_matches([' ', '-', '_', '.']);
+
+        let Some(guarded_key) = switches
+            .keys()
+            .find(|key| *key != cover_key && key.eq_ignore_ascii_case(base))
+        else {
+            continue;
+        };
+
+        if taken.contains(cover_key)
+            || taken.contains(guarded_key)
+            || composites.contains_key(guarded_key)
+        {
+            continue;
+        }
+
+        // The cover rests closed at its default position and opens towards the far limit
+        let cover = &switches[cover_key];
+        let closed = cover.default_position;
+        let open = if (cover.upper_limit - closed).abs() >= (cover.lower_limit - closed).abs() {
+            cover.upper_limit
+        } else {
+            cover.lower_limit
+        };
+
+        let composite = CompositeControl {
+            cover_switch: cover_key.clone(),
+            guarded_switch: guarded_key.clone(),
+            cover_closed_position: closed,
+            cover_open_position: open,
+            description: String::new(),
+        };
+
+        taken.insert(cover_key.clone());
+        taken.insert(guarded_key.clone());
+        detected.insert(guarded_key.clone(), composite);
+    }
+
+    println!("Detected {} composite control(s)", detected.len());
+
+    for (name, composite) in &detected {
+        composites.insert(name.clone(), composite.clone());
+    }
+    utils::write_section(&mut json_data, COMPOSITES_SECTION, &composites)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(detected)
+}
+
+fn strip_suffix_ignore_case<'a>(value: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = value.len().checked_sub(suffix.len())?;
+    if !value.is_char_boundary(split) || !value[split..].eq_ignore_ascii_case(suffix) {
+        return None;
+    }
+    Some(&value[..split])
+}
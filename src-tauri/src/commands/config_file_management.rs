@@ -7,13 +7,19 @@ use tauri_plugin_opener::OpenerExt;
 
 use crate::OUTPUT_FOLDER_PATH;
 
-use crate::models::FullConfigFile;
+use crate::migration;
+use crate::model_scan;
+use crate::models::{FullConfigFile, SwitchType};
+use crate::switch_defaults;
+use crate::switch_types;
+use crate::utils;
 
 #[tauri::command]
 pub fn create_new_config_file(
     app_handle: tauri::AppHandle,
     plane_name: String,
     model_file_path: String,
+    seed_from_model: Option<bool>,
 ) -> Result<(), String> {
     let app_data_dir = app_handle
         .path()
@@ -29,12 +35,20 @@ pub fn create_new_config_file(
     // Create the full file path
     let file_path = plane_config_folder_path.join(format!("{}.json", plane_name));
 
-    // Build the JSON structure with the provided planeName, modelPath, and an empty "switches" object.
+    // Optionally seed the switches from the model scan, otherwise start with an empty object
+    let switches = if seed_from_model.unwrap_or(false) {
+        seed_switches_from_model(&app_handle, &model_file_path)?
+    } else {
+        serde_json::json!({})
+    };
+
+    // Build the JSON structure with the provided planeName, modelPath, and the switches.
     let initial_content = serde_json::json!({
+        "schemaVersion": migration::CURRENT_SCHEMA_VERSION,
         "planeName": plane_name,
         "modelPath": model_file_path,
         "teleportZones": {},
-        "switches": {}
+        "switches": switches
     });
 
     // Convert the JSON object into a pretty-printed string
@@ -48,24 +62,66 @@ pub fn create_new_config_file(
     Ok(())
 }
 
+/// Give every switch detected in the model an unreviewed entry filled with its type defaults
+fn seed_switches_from_model(
+    app_handle: &tauri::AppHandle,
+    model_file_path: &str,
+) -> Result<serde_json::Value, String> {
+    let profile = switch_defaults::load_profile(app_handle)?;
+    let tags = switch_types::load_detection_tags(app_handle)?;
+    let scan = model_scan::scan_model_file(model_file_path, &tags)?;
+
+    let mut switches = serde_json::Map::new();
+    for nodes in &scan.switches {
+        let switch_type: SwitchType =
+            utils::deserialize_json(serde_json::json!(nodes.switch_type))?;
+        let defaults = switch_defaults::defaults_for(&profile, &switch_type);
+
+        let switch_data = switch_defaults::seeded_switch(switch_type, nodes, &defaults);
+        switches.insert(
+            nodes.pretty_name.clone(),
+            utils::serialize_to_json(&switch_data)?,
+        );
+    }
+
+    println!(
+        "Seeded {} switch(es) from '{}'",
+        switches.len(),
+        model_file_path
+    );
+
+    Ok(serde_json::Value::Object(switches))
+}
+
 #[tauri::command]
 pub fn get_current_config_file(app_handle: tauri::AppHandle) -> String {
     let state = app_handle.state::<Mutex<AppData>>();
 
     let state = state.lock().unwrap();
 
-    return state.current_json_file.clone();
+    state.current_json_file.clone()
 }
 
 #[tauri::command]
-pub fn set_current_config_file(app_handle: tauri::AppHandle, file_name: String) {
+pub fn set_current_config_file(
+    app_handle: tauri::AppHandle,
+    file_name: String,
+) -> Result<(), String> {
     println!("Setting current file to: {:?}", &file_name);
 
+    // Bring older files up to date as soon as they're opened
+    if !file_name.is_empty() {
+        let file_path = utils::build_config_file_path(&app_handle, &file_name)?;
+        migration::migrate_config_file(&file_path)?;
+    }
+
     let state = app_handle.state::<Mutex<AppData>>();
 
     let mut state = state.lock().unwrap();
 
     state.current_json_file = file_name;
+
+    Ok(())
 }
 
 #[tauri::command]
@@ -75,6 +131,7 @@ pub fn get_current_config_file_contents(app_handle: tauri::AppHandle) -> Result<
 
     if state.current_json_file.is_empty() {
         let default_config = serde_json::json!({
+            "schemaVersion": migration::CURRENT_SCHEMA_VERSION,
             "planeName": "",
             "modelPath": "",
             "teleportZones": {},
@@ -97,8 +154,12 @@ pub fn get_current_config_file_contents(app_handle: tauri::AppHandle) -> Result<
     let file_contents = std::fs::read_to_string(current_json_file_path)
         .map_err(|e| format!("failed to read file: {}", e))?;
 
-    let config_file: FullConfigFile = serde_json::from_str(&file_contents)
-        .map_err(|e| format!("failed to parse json: {}", e))?;
+    let mut json_data: serde_json::Value =
+        serde_json::from_str(&file_contents).map_err(|e| format!("failed to parse json: {}", e))?;
+    migration::migrate_config(&mut json_data)?;
+
+    let config_file: FullConfigFile =
+        serde_json::from_value(json_data).map_err(|e| format!("failed to parse json: {}", e))?;
 
     serde_json::to_string_pretty(&config_file)
         .map_err(|e| format!("failed to serialize to json: {}", e))
@@ -0,0 +1,12 @@
+use crate::config_schema;
+use crate::utils;
+use crate::CONFIG_SCHEMA_FILE;
+use serde_json::Value as Json;
+
+/// The JSON Schema of the plane config format. It is also written next to the configs, so
+/// external tools and editors can validate files without the app.
+#[tauri::command]
+pub fn get_config_schema(app_handle: tauri::AppHandle) -> Result<Json, String> {
+    let schema_path = utils::build_config_file_path(&app_handle, CONFIG_SCHEMA_FILE)?;
+    config_schema::write_config_schema(&schema_path)
+}
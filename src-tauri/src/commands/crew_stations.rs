@@ -0,0 +1,98 @@
+use crate::models::{CrewStation, FullConfigFile, ValidationSeverity};
+use crate::utils;
+use crate::validation;
+
+const CREW_STATIONS_SECTION: &str = "crewStations";
+
+/// Add or replace a crew station. Its zones, switches and panels must already exist.
+#[tauri::command]
+pub fn set_crew_station(
+    app_handle: tauri::AppHandle,
+    station_key: String,
+    station: CrewStation,
+) -> Result<String, String> {
+    println!("Setting crew station '{}': {:?}", &station_key, &station);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let config: FullConfigFile = utils::deserialize_json(json_data.clone())?;
+
+    let errors: Vec<String> = validation::validate_crew_station(&station_key, &station, &config)
+        .into_iter()
+        .filter(|issue| issue.severity == ValidationSeverity::Error)
+        .map(|issue| issue.message)
+        .collect();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Crew station '{}' is invalid: {}",
+            station_key,
+            errors.join("; ")
+        ));
+    }
+
+    // Ensure crewStations section exists
+    let stations = utils::ensure_section_exists(&mut json_data, CREW_STATIONS_SECTION)?;
+
+    stations
+        .as_object_mut()
+        .unwrap()
+        .insert(station_key.clone(), utils::serialize_to_json(&station)?);
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Crew station '{}' saved successfully", station_key))
+}
+
+#[tauri::command]
+pub fn remove_crew_station(
+    app_handle: tauri::AppHandle,
+    station_key: String,
+) -> Result<String, String> {
+    println!("Removing crew station: {}", station_key);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    // Ensure crewStations section exists
+    let stations = utils::ensure_section_exists(&mut json_data, CREW_STATIONS_SECTION)?;
+
+    if stations
+        .as_object_mut()
+        .unwrap()
+        .remove(&station_key)
+        .is_none()
+    {
+        return Err(format!("Crew station '{}' not found", station_key));
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!(
+        "Crew station '{}' removed successfully",
+        station_key
+    ))
+}
+
+/// Keys of the switches a crew station can operate, for scoping training scenarios per role
+#[tauri::command]
+pub fn get_station_switches(
+    app_handle: tauri::AppHandle,
+    station_key: String,
+) -> Result<Vec<String>, String> {
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+    let config: FullConfigFile = utils::deserialize_json(json_data)?;
+
+    let station = config
+        .crew_stations
+        .get(&station_key)
+        .ok_or_else(|| format!("Crew station '{}' not found", station_key))?;
+
+    Ok(station
+        .reachable_switches(&config.panels)
+        .into_iter()
+        .filter(|key| config.switches.contains_key(key))
+        .collect())
+}
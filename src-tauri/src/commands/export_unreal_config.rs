@@ -0,0 +1,24 @@
+use crate::geometry;
+use crate::models::FullConfigFile;
+use crate::unreal_export;
+use crate::utils;
+use crate::validation;
+
+/// Write the engine-facing version of the current config and return where it was written
+#[tauri::command]
+pub fn export_unreal_config(app_handle: tauri::AppHandle) -> Result<String, String> {
+    let (current_file, _, json_data) = utils::get_current_config(&app_handle)?;
+    let geometry = geometry::load_model_geometry(utils::get_model_path(&json_data)?)?;
+    let config: FullConfigFile = utils::deserialize_json(json_data)?;
+
+    validation::ensure_valid_teleport_zones(&config, &geometry)?;
+
+    let export = unreal_export::build_export(&config)?;
+
+    let export_path = utils::build_export_file_path(&app_handle, &current_file)?;
+    utils::save_json_file(&export_path, &export)?;
+
+    println!("Exported '{}' to {:?}", current_file, &export_path);
+
+    Ok(export_path.to_string_lossy().into_owned())
+}
@@ -0,0 +1,80 @@
+use crate::models::{Interlock, SwitchData, ValidationSeverity};
+use crate::utils;
+use crate::validation;
+use std::collections::HashMap;
+
+const INTERLOCKS_SECTION: &str = "interlocks";
+
+/// Add or replace an interlock. It must only reference existing switches and positions;
+/// circular dependencies are left to `validate_current_config` so chains can be built up.
+#[tauri::command]
+pub fn set_interlock(
+    app_handle: tauri::AppHandle,
+    interlock_name: String,
+    interlock: Interlock,
+) -> Result<String, String> {
+    println!("Setting interlock '{}': {:?}", &interlock_name, &interlock);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+
+    let errors: Vec<String> =
+        validation::validate_interlock(&interlock_name, &interlock, &switches)
+            .into_iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+            .map(|issue| issue.message)
+            .collect();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Interlock '{}' is invalid: {}",
+            interlock_name,
+            errors.join("; ")
+        ));
+    }
+
+    // Ensure interlocks section exists
+    let interlocks = utils::ensure_section_exists(&mut json_data, INTERLOCKS_SECTION)?;
+
+    interlocks.as_object_mut().unwrap().insert(
+        interlock_name.clone(),
+        utils::serialize_to_json(&interlock)?,
+    );
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Interlock '{}' saved successfully", interlock_name))
+}
+
+#[tauri::command]
+pub fn remove_interlock(
+    app_handle: tauri::AppHandle,
+    interlock_name: String,
+) -> Result<String, String> {
+    println!("Removing interlock: {}", interlock_name);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    // Ensure interlocks section exists
+    let interlocks = utils::ensure_section_exists(&mut json_data, INTERLOCKS_SECTION)?;
+
+    if interlocks
+        .as_object_mut()
+        .unwrap()
+        .remove(&interlock_name)
+        .is_none()
+    {
+        return Err(format!("Interlock '{}' not found", interlock_name));
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!(
+        "Interlock '{}' removed successfully",
+        interlock_name
+    ))
+}
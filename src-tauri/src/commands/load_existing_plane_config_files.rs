@@ -2,7 +2,7 @@ use std::fs;
 use std::io::Read;
 use tauri::Manager;
 
-use crate::OUTPUT_FOLDER_PATH;
+use crate::{CONFIG_SCHEMA_FILE, OUTPUT_FOLDER_PATH};
 
 use crate::models::PlaneConfigFile;
 
@@ -27,10 +27,15 @@ pub fn load_existing_plane_config_files(app_handle: tauri::AppHandle) -> Vec<Pla
 
     for entry in entries.filter_map(Result::ok) {
         let path: std::path::PathBuf = entry.path();
-        if path.is_file() && path.extension().map_or(false, |ext| ext == "json") {
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
             // Get the file name.
             let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
 
+            // The schema lives next to the configs but isn't one.
+            if file_name == CONFIG_SCHEMA_FILE {
+                continue;
+            }
+
             // Read and parse the JSON file to extract the modelPath.
             let mut file_content = String::new();
             if let Ok(mut file) = fs::File::open(&path) {
@@ -1,63 +1,14 @@
-use crate::models::{ParsedGLBData, SwitchItem};
+use crate::model_scan;
+use crate::models::{NodePairing, ParsedGLBData, SwitchItem};
+use crate::switch_types;
 use crate::AppData;
 use crate::OUTPUT_FOLDER_PATH;
 use base64::Engine;
-use gltf;
-use regex::Regex;
 use std::collections::HashSet;
 use std::fs;
 use std::sync::Mutex;
 use tauri::Manager;
 
-fn traverse_node(
-    node: gltf::Node,
-    tags: &[&str],
-    switches: &mut Vec<SwitchItem>,
-    configured_switches: &HashSet<String>,
-    all_node_names: &mut Vec<String>,
-    depth: usize,
-) {
-    if let Some(name) = node.name() {
-        let raw_node_name = name.to_string();
-        
-        // Add every node name to the all_node_names list
-        all_node_names.push(raw_node_name.clone());
-        
-        let mut is_switch = false;
-        for tag in tags {
-            let pattern = format!("(?i){}", regex::escape(tag));
-            let re = Regex::new(&pattern).expect("Failed to compile regex pattern");
-            if re.is_match(name) {
-                is_switch = true;
-                
-                // Create the pretty name by removing tag and "-Collider"
-                let mut pretty_name = re.replace_all(name, "").to_string();
-                pretty_name = pretty_name.replace("-Collider", "");
-                pretty_name = pretty_name.trim().to_string();
-
-                // Check if this switch is already configured
-                let is_configured = configured_switches.contains(&pretty_name);
-
-                // Get switch type from the tag
-                let switch_type = tag.trim_start_matches('-').to_lowercase();
-
-                // Create the SwitchItem, making sure to store both raw and pretty names
-                switches.push(SwitchItem {
-                    mesh_name: raw_node_name.clone(), // This is already the raw node name
-                    pretty_name,                      // This is the processed name
-                    is_configured,
-                    switch_type,
-                    raw_node_name: raw_node_name.clone(), // Store the raw node name in the new field
-                });
-                break;
-            }
-        }
-    }
-    for child in node.children() {
-        traverse_node(child, tags, switches, configured_switches, all_node_names, depth + 1);
-    }
-}
-
 #[tauri::command]
 pub async fn load_plane_model_data(app_handle: tauri::AppHandle) -> Result<ParsedGLBData, String> {
     let state = app_handle.state::<Mutex<AppData>>();
@@ -84,7 +35,7 @@ pub async fn load_plane_model_data(app_handle: tauri::AppHandle) -> Result<Parse
     let file_contents = fs::read_to_string(&current_json_file_path)
         .map_err(|e| format!("failed to read config file: {}", e))?;
 
-    let mut json_value: serde_json::Value = serde_json::from_str(&file_contents)
+    let json_value: serde_json::Value = serde_json::from_str(&file_contents)
         .map_err(|e| format!("failed to parse config JSON: {}", e))?;
 
     let model_path = json_value
@@ -92,57 +43,62 @@ pub async fn load_plane_model_data(app_handle: tauri::AppHandle) -> Result<Parse
         .and_then(|v| v.as_str())
         .ok_or("modelPath not found in the config file")?;
 
-    // Get already configured switches to check against
-    let configured_switches: HashSet<String> =
-        if let Some(switches_obj) = json_value.get("switches").and_then(|v| v.as_object()) {
-            switches_obj.keys().map(|k| k.to_string()).collect()
-        } else {
-            HashSet::new()
-        };
+    // Get already configured switches to check against. Generated entries that nobody has
+    // reviewed yet don't count as configured.
+    let configured_switches: HashSet<String> = if let Some(switches_obj) =
+        json_value.get("switches").and_then(|v| v.as_object())
+    {
+        switches_obj
+            .iter()
+            .filter(|(_, v)| v.get("reviewStatus").and_then(|s| s.as_str()) != Some("unreviewed"))
+            .map(|(k, _)| k.to_string())
+            .collect()
+    } else {
+        HashSet::new()
+    };
 
     // Read the model data
     let data = fs::read(model_path).map_err(|e| e.to_string())?;
     let model_base64 = base64::engine::general_purpose::STANDARD.encode(&data);
 
     // Parse the GLTF file
-    let gltf = gltf::Gltf::from_slice(&data).map_err(|e| e.to_string())?;
+    let tags = switch_types::load_detection_tags(&app_handle)?;
+    let scan = model_scan::scan_model(&data, &tags)?;
 
-    let mut switches = Vec::new();
-    let mut all_node_names = Vec::new();
-    let tags = ["-Dial", "-Button", "-Lever"];
-
-    for scene in gltf.scenes() {
-        for node in scene.nodes() {
-            traverse_node(node, &tags, &mut switches, &configured_switches, &mut all_node_names, 0);
-        }
-    }
-
-    println!("parse_glb: found {} switch(es).", switches.len());
-    println!("parse_glb: found {} total node(s).", all_node_names.len());
-
-    // Extract switch node names to filter them out
-    let switch_node_names: HashSet<String> = switches.iter()
-        .map(|s| s.raw_node_name.clone())
-        .collect();
-
-    // Filter out switch nodes to get only non-switch nodes
-    let non_switch_nodes: Vec<String> = all_node_names
+    let switches: Vec<SwitchItem> = scan
+        .switches
         .into_iter()
-        .filter(|name| !switch_node_names.contains(name))
+        .map(|nodes| {
+            let pairing = nodes.pairing();
+            if pairing != NodePairing::Paired {
+                println!(
+                    "parse_glb: switch '{}' is {:?}.",
+                    nodes.pretty_name, pairing
+                );
+            }
+
+            // Prefer the visual mesh for display, fall back to the collider
+            let raw_node_name = nodes
+                .visual
+                .clone()
+                .or_else(|| nodes.collider.clone())
+                .unwrap_or_default();
+
+            SwitchItem {
+                mesh_name: raw_node_name.clone(),
+                is_configured: configured_switches.contains(&nodes.pretty_name),
+                pretty_name: nodes.pretty_name,
+                switch_type: nodes.switch_type,
+                raw_node_name,
+                visual_node_name: nodes.visual,
+                collider_node_name: nodes.collider,
+                pairing,
+            }
+        })
         .collect();
 
-    // Update the JSON value with non-switch node names
-    if json_value.is_object() {
-        let json_obj = json_value.as_object_mut().unwrap();
-        json_obj.insert("nonSwitchRawNodeNames".to_string(), serde_json::to_value(non_switch_nodes).unwrap());
-        
-        // Write the updated JSON back to the file
-        let updated_json = serde_json::to_string_pretty(&json_value)
-            .map_err(|e| format!("Failed to serialize updated JSON: {}", e))?;
-            
-        std::fs::write(&current_json_file_path, updated_json)
-            .map_err(|e| format!("Failed to write JSON file: {}", e))?;
-    }
+    println!("parse_glb: found {} switch(es).", switches.len());
+    println!("parse_glb: found {} total node(s).", scan.node_names.len());
 
     Ok(ParsedGLBData {
         switches,
@@ -0,0 +1,189 @@
+use crate::mirroring::{self, NameMirror};
+use crate::model_scan;
+use crate::models::{
+    default_name_swaps, MirrorPlane, NameSwap, ReviewStatus, SwitchData, TeleportZone, ZoneArray,
+};
+use crate::switch_types;
+use crate::utils;
+use crate::validation;
+use std::collections::{HashMap, HashSet};
+
+/// Mirror teleport zones across a plane. Each copy is named by swapping the name fragments,
+/// `Left` and `Right` by default. Returns the names of the mirrored zones.
+#[tauri::command]
+pub fn mirror_teleport_zones(
+    app_handle: tauri::AppHandle,
+    zone_names: Vec<String>,
+    plane: MirrorPlane,
+    name_swaps: Option<Vec<NameSwap>>,
+    overwrite: Option<bool>,
+) -> Result<Vec<String>, String> {
+    println!(
+        "Mirroring teleport zones {:?} across {:?}",
+        &zone_names, &plane
+    );
+
+    let names = NameMirror::new(&name_swaps.unwrap_or_else(default_name_swaps))?;
+    let overwrite = overwrite.unwrap_or(false);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let mut zones: HashMap<String, TeleportZone> =
+        utils::read_section(&json_data, "teleportZones")?;
+
+    let mut mirrored = Vec::new();
+    for name in &zone_names {
+        let zone = zones
+            .get(name)
+            .ok_or_else(|| format!("Teleport zone '{}' not found", name))?;
+        let mirrored_name = names
+            .mirror(name)
+            .ok_or_else(|| format!("Teleport zone '{}' has no name part to swap", name))?;
+        mirrored.push((mirrored_name, mirroring::mirror_zone(zone, &plane)));
+    }
+
+    for (name, zone) in &mirrored {
+        if zones.contains_key(name) && !overwrite && !zone_names.contains(name) {
+            return Err(format!("Teleport zone '{}' already exists", name));
+        }
+        zones.insert(name.clone(), zone.clone());
+    }
+
+    utils::write_section(&mut json_data, "teleportZones", &zones)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(mirrored.into_iter().map(|(name, _)| name).collect())
+}
+
+/// Copy a teleport zone along a line or grid, e.g. one zone per passenger seat. Copies are
+/// named `<zone>_<column>` for a line and `<zone>_<row>_<column>` for a grid.
+#[tauri::command]
+pub fn array_teleport_zone(
+    app_handle: tauri::AppHandle,
+    zone_name: String,
+    array: ZoneArray,
+) -> Result<Vec<String>, String> {
+    println!(
+        "Creating array of teleport zone '{}': {:?}",
+        &zone_name, &array
+    );
+
+    if array.rows == 0 || array.columns == 0 {
+        return Err("An array needs at least one row and one column".to_string());
+    }
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let mut zones: HashMap<String, TeleportZone> =
+        utils::read_section(&json_data, "teleportZones")?;
+    let original = zones
+        .get(&zone_name)
+        .cloned()
+        .ok_or_else(|| format!("Teleport zone '{}' not found", zone_name))?;
+
+    let mut created = Vec::new();
+    for (row, column, position) in mirroring::array_positions(original.position, &array) {
+        let name = if array.rows == 1 {
+            format!("{}_{}", zone_name, column)
+        } else {
+            format!("{}_{}_{}", zone_name, row, column)
+        };
+        if zones.contains_key(&name) {
+            return Err(format!("Teleport zone '{}' already exists", name));
+        }
+
+        let mut zone = original.clone();
+        zone.position = position;
+        zones.insert(name.clone(), zone);
+        created.push(name);
+    }
+
+    utils::write_section(&mut json_data, "teleportZones", &zones)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(created)
+}
+
+/// Copy switch configs onto their mirrored counterparts, e.g. a left panel onto the right one.
+/// Keys and node names are rewritten with the name swaps and the mirrored raw node must exist
+/// in the model. Limits are copied as they are, since each node moves in its own local frame.
+/// The copies are marked unreviewed. Returns the keys of the mirrored switches.
+#[tauri::command]
+pub fn mirror_switches(
+    app_handle: tauri::AppHandle,
+    switch_keys: Vec<String>,
+    name_swaps: Option<Vec<NameSwap>>,
+    overwrite: Option<bool>,
+) -> Result<Vec<String>, String> {
+    println!("Mirroring switches {:?}", &switch_keys);
+
+    let names = NameMirror::new(&name_swaps.unwrap_or_else(default_name_swaps))?;
+    let overwrite = overwrite.unwrap_or(false);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let registry = switch_types::load_registry(&app_handle)?;
+    let scan = model_scan::scan_model_file(utils::get_model_path(&json_data)?, &[])?;
+    let model_nodes: HashSet<&String> = scan.node_names.iter().collect();
+
+    let mut switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+
+    let mirror_name = |name: &str, what: &str| {
+        names
+            .mirror(name)
+            .ok_or_else(|| format!("{} '{}' has no name part to swap", what, name))
+    };
+
+    let mut mirrored = Vec::new();
+    for key in &switch_keys {
+        let source = switches
+            .get(key)
+            .ok_or_else(|| format!("Switch '{}' not found", key))?;
+
+        let mirrored_key = mirror_name(key, "Switch")?;
+        if switches.contains_key(&mirrored_key) && !overwrite {
+            return Err(format!("Switch '{}' already exists", mirrored_key));
+        }
+
+        let mut switch_data = source.clone();
+        switch_data.raw_node_name = mirror_name(&source.raw_node_name, "Node")?;
+        if !model_nodes.contains(&switch_data.raw_node_name) {
+            return Err(format!(
+                "Node '{}' for switch '{}' is not in the model",
+                switch_data.raw_node_name, mirrored_key
+            ));
+        }
+        switch_data.visual_node_name = source
+            .visual_node_name
+            .as_deref()
+            .map(|node| mirror_name(node, "Node"))
+            .transpose()?;
+        switch_data.collider_node_name = source
+            .collider_node_name
+            .as_deref()
+            .map(|node| mirror_name(node, "Node"))
+            .transpose()?;
+        switch_data.review_status = ReviewStatus::Unreviewed;
+
+        validation::ensure_valid_switch(&mirrored_key, &switch_data, &registry)?;
+        mirrored.push((mirrored_key, switch_data));
+    }
+
+    for (key, switch_data) in &mirrored {
+        switches.insert(key.clone(), switch_data.clone());
+    }
+
+    utils::write_section(&mut json_data, "switches", &switches)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(mirrored.into_iter().map(|(key, _)| key).collect())
+}
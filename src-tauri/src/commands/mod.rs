@@ -1,20 +1,66 @@
 mod add_new_switch;
 mod add_new_teleport_zone;
+mod bulk_update_switches;
+mod composite_controls;
 mod config_file_management;
+mod config_schema;
+mod crew_stations;
+mod export_unreal_config;
+mod interlocks;
 mod load_existing_plane_config_files;
 mod load_plane_model_data;
+mod mirror_tools;
+mod node_classification;
+mod panels;
 mod remove_teleport_zone;
 mod rename_teleport_zone;
+mod sound_library;
+mod switch_rules;
+mod switch_sheet;
+mod switch_type_defaults;
+mod switch_types;
+mod teleport_links;
+mod teleport_zone_checks;
+mod teleport_zone_csv;
+mod unreal_codegen;
 mod update_teleport_zone;
+mod validate_config;
 
 pub use add_new_switch::add_new_switch;
 pub use add_new_teleport_zone::add_new_teleport_zone;
+pub use bulk_update_switches::bulk_update_switches;
+pub use composite_controls::{
+    detect_composite_controls, remove_composite_control, set_composite_control,
+};
 pub use config_file_management::{
     create_new_config_file, get_current_config_file, get_current_config_file_contents, open_file,
     open_plane_config_folder, set_current_config_file,
 };
+pub use config_schema::get_config_schema;
+pub use crew_stations::{get_station_switches, remove_crew_station, set_crew_station};
+pub use export_unreal_config::export_unreal_config;
+pub use interlocks::{remove_interlock, set_interlock};
 pub use load_existing_plane_config_files::load_existing_plane_config_files;
 pub use load_plane_model_data::load_plane_model_data;
+pub use mirror_tools::{array_teleport_zone, mirror_switches, mirror_teleport_zones};
+pub use node_classification::{
+    classify_model_nodes, set_node_classification, set_node_classification_rules,
+};
+pub use panels::{derive_panels_from_model, remove_panel, set_panel};
 pub use remove_teleport_zone::remove_teleport_zone;
 pub use rename_teleport_zone::rename_teleport_zone;
+pub use sound_library::{remove_sound_asset, set_sound_asset};
+pub use switch_rules::{
+    apply_switch_rules, get_switch_rules, preview_switch_rules, set_switch_rules,
+};
+pub use switch_sheet::{export_switch_sheet, import_switch_sheet};
+pub use switch_type_defaults::{
+    get_switch_type_defaults, reset_switches_to_type_defaults, set_switch_type_defaults,
+};
+pub use switch_types::{get_switch_types, remove_switch_type, set_switch_type};
+pub use teleport_links::{remove_teleport_link, set_default_spawn_zone, set_teleport_link};
+pub use teleport_zone_checks::{check_teleport_zone_floors, validate_teleport_zones};
+pub use teleport_zone_csv::{export_teleport_zones, import_teleport_zones};
+pub use unreal_codegen::{export_unreal_data_tables, generate_unreal_code};
 pub use update_teleport_zone::update_teleport_zone;
+pub use validate_config::validate_current_config;
@@ -0,0 +1,128 @@
+use crate::model_scan::{self, ModelScan};
+use crate::models::{
+    ClassificationSource, ClassifiedNode, NodeCategory, NodeClassification, NodeClassificationRule,
+};
+use crate::switch_types;
+use crate::utils;
+use std::collections::HashSet;
+
+const NODE_CLASSIFICATION_SECTION: &str = "nodeClassification";
+
+/// Classify every node of the current model. This never writes to the config file.
+#[tauri::command]
+pub fn classify_model_nodes(app_handle: tauri::AppHandle) -> Result<Vec<ClassifiedNode>, String> {
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+
+    let classification: NodeClassification =
+        utils::read_section(&json_data, NODE_CLASSIFICATION_SECTION)?;
+    let tags = switch_types::load_detection_tags(&app_handle)?;
+    let scan = model_scan::scan_model_file(utils::get_model_path(&json_data)?, &tags)?;
+
+    classify_nodes(&scan, &classification)
+}
+
+/// Manually assign a category to nodes, or clear their manual assignment when `category` is None
+#[tauri::command]
+pub fn set_node_classification(
+    app_handle: tauri::AppHandle,
+    raw_node_names: Vec<String>,
+    category: Option<NodeCategory>,
+) -> Result<String, String> {
+    println!(
+        "Classifying {} node(s) as {:?}",
+        raw_node_names.len(),
+        category
+    );
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let mut classification: NodeClassification =
+        utils::read_section(&json_data, NODE_CLASSIFICATION_SECTION)?;
+
+    for raw_node_name in raw_node_names {
+        match category {
+            Some(category) => {
+                classification.manual.insert(raw_node_name, category);
+            }
+            None => {
+                classification.manual.remove(&raw_node_name);
+            }
+        }
+    }
+
+    utils::write_section(&mut json_data, NODE_CLASSIFICATION_SECTION, &classification)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok("Node classification updated successfully".to_string())
+}
+
+/// Replace the classification rules. Rules are evaluated in order and the first match wins.
+#[tauri::command]
+pub fn set_node_classification_rules(
+    app_handle: tauri::AppHandle,
+    rules: Vec<NodeClassificationRule>,
+) -> Result<String, String> {
+    println!("Setting {} node classification rule(s)", rules.len());
+
+    // Reject the whole set if any pattern is invalid
+    for rule in &rules {
+        utils::compile_node_pattern(&rule.pattern)?;
+    }
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let mut classification: NodeClassification =
+        utils::read_section(&json_data, NODE_CLASSIFICATION_SECTION)?;
+    classification.rules = rules;
+
+    utils::write_section(&mut json_data, NODE_CLASSIFICATION_SECTION, &classification)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok("Node classification rules updated successfully".to_string())
+}
+
+/// Resolve the category of every scanned node: manual assignment, then the first matching
+/// rule, then switch detection, and finally `static`.
+pub fn classify_nodes(
+    scan: &ModelScan,
+    classification: &NodeClassification,
+) -> Result<Vec<ClassifiedNode>, String> {
+    let rules = classification
+        .rules
+        .iter()
+        .map(|rule| Ok((utils::compile_node_pattern(&rule.pattern)?, rule.category)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let switch_node_names: HashSet<&String> =
+        scan.switches.iter().flat_map(|s| s.node_names()).collect();
+
+    let classified = scan
+        .node_names
+        .iter()
+        .map(|name| {
+            let (category, source) = if let Some(category) = classification.manual.get(name) {
+                (*category, ClassificationSource::Manual)
+            } else if let Some((_, category)) = rules.iter().find(|(re, _)| re.is_match(name)) {
+                (*category, ClassificationSource::Rule)
+            } else if switch_node_names.contains(name) {
+                (NodeCategory::Switch, ClassificationSource::Detected)
+            } else {
+                (NodeCategory::Static, ClassificationSource::Default)
+            };
+
+            ClassifiedNode {
+                raw_node_name: name.clone(),
+                category,
+                source,
+            }
+        })
+        .collect();
+
+    Ok(classified)
+}
@@ -0,0 +1,261 @@
+use crate::model_scan::{self, ModelScan};
+use crate::models::{CrewStation, Panel, PanelGroup, SwitchData};
+use crate::switch_types;
+use crate::utils;
+use std::collections::HashMap;
+
+const PANELS_SECTION: &str = "panels";
+const UNASSIGNED_PANEL: &str = "Unassigned";
+
+/// Add or replace a panel. Its switches must exist, and any of them already on another panel
+/// are moved off that panel, since a switch belongs to one panel only.
+#[tauri::command]
+pub fn set_panel(
+    app_handle: tauri::AppHandle,
+    panel_key: String,
+    panel: Panel,
+) -> Result<String, String> {
+    println!("Setting panel '{}': {:?}", &panel_key, &panel);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+    let assigned = panel.all_switches();
+
+    let unknown: Vec<&str> = assigned
+        .iter()
+        .filter(|key| !switches.contains_key(key.as_str()))
+        .map(|key| key.as_str())
+        .collect();
+    if !unknown.is_empty() {
+        return Err(format!(
+            "Panel '{}' references unknown switches: {}",
+            panel_key,
+            unknown.join(", ")
+        ));
+    }
+
+    let mut panels: HashMap<String, Panel> = utils::read_section(&json_data, PANELS_SECTION)?;
+    for (key, other) in panels.iter_mut() {
+        if *key != panel_key {
+            other.remove_switches(&assigned);
+        }
+    }
+    panels.insert(panel_key.clone(), panel.clone());
+
+    utils::write_section(&mut json_data, PANELS_SECTION, &panels)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Panel '{}' saved successfully", panel_key))
+}
+
+#[tauri::command]
+pub fn remove_panel(app_handle: tauri::AppHandle, panel_key: String) -> Result<String, String> {
+    println!("Removing panel: {}", panel_key);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let stations: HashMap<String, CrewStation> = utils::read_section(&json_data, "crewStations")?;
+    let mut users: Vec<&String> = stations
+        .iter()
+        .filter(|(_, station)| station.panels.contains(&panel_key))
+        .map(|(key, _)| key)
+        .collect();
+    if !users.is_empty() {
+        users.sort();
+        return Err(format!(
+            "Panel '{}' is still used by crew stations: {}",
+            panel_key,
+            users
+                .iter()
+                .map(|k| k.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    // Ensure panels section exists
+    let panels = utils::ensure_section_exists(&mut json_data, PANELS_SECTION)?;
+
+    if panels.as_object_mut().unwrap().remove(&panel_key).is_none() {
+        return Err(format!("Panel '{}' not found", panel_key));
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Panel '{}' removed successfully", panel_key))
+}
+
+/// Group the configured switches into panels following the model's node hierarchy.
+/// Existing panels are kept unless `replace_existing` is set, and so are their switches:
+/// derived panels only pick up switches that aren't on a panel yet.
+#[tauri::command]
+pub fn derive_panels_from_model(
+    app_handle: tauri::AppHandle,
+    replace_existing: Option<bool>,
+) -> Result<HashMap<String, Panel>, String> {
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let tags = switch_types::load_detection_tags(&app_handle)?;
+    let scan = model_scan::scan_model_file(utils::get_model_path(&json_data)?, &tags)?;
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+
+    let derived = derive_panels(&scan, &switches);
+
+    let mut panels: HashMap<String, Panel> = if replace_existing.unwrap_or(false) {
+        HashMap::new()
+    } else {
+        utils::read_section(&json_data, PANELS_SECTION)?
+    };
+    let kept: Vec<String> = panels
+        .values()
+        .flat_map(|panel| panel.all_switches())
+        .cloned()
+        .collect();
+    let kept: Vec<&String> = kept.iter().collect();
+    for (key, mut panel) in derived {
+        panel.remove_switches(&kept);
+        if panel.all_switches().is_empty() {
+            continue;
+        }
+        match panels.get_mut(&key) {
+            // A panel of the same name keeps its layout and gains the unplaced switches
+            Some(existing) => existing.merge(panel),
+            None => {
+                panels.insert(key, panel);
+            }
+        }
+    }
+
+    utils::write_section(&mut json_data, PANELS_SECTION, &panels)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(panels)
+}
+
+/// The first ancestor below the root chain shared by every switch becomes the panel, deeper
+/// ancestors become nested groups, and switches keep the order they have in the model.
+fn derive_panels(
+    scan: &ModelScan,
+    switches: &HashMap<String, SwitchData>,
+) -> HashMap<String, Panel> {
+    let node_order: HashMap<&String, usize> = scan
+        .node_names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (name, index))
+        .collect();
+
+    let mut ordered: Vec<(&String, &SwitchData)> = switches.iter().collect();
+    ordered.sort_by_key(|(key, switch_data)| {
+        (
+            node_order
+                .get(&switch_data.raw_node_name)
+                .copied()
+                .unwrap_or(usize::MAX),
+            key.to_string(),
+        )
+    });
+
+    // Ancestor paths from the root down
+    let paths: Vec<(&String, Vec<&String>)> = ordered
+        .iter()
+        .map(|(key, switch_data)| {
+            let mut path = scan.ancestors(&switch_data.raw_node_name);
+            path.reverse();
+            (*key, path)
+        })
+        .collect();
+
+    let shared_depth = shared_prefix_len(paths.iter().map(|(_, path)| path.as_slice()));
+
+    let mut panels: HashMap<String, Panel> = HashMap::new();
+    for (switch_key, path) in &paths {
+        let relative = &path[shared_depth.min(path.len())..];
+
+        // When the whole path is shared (e.g. a single panel) fall back to the direct parent
+        let (panel_key, source_node, groups_path) = match (relative.split_first(), path.last()) {
+            (Some((panel_node, rest)), _) => {
+                ((*panel_node).clone(), Some((*panel_node).clone()), rest)
+            }
+            (None, Some(parent)) => ((*parent).clone(), Some((*parent).clone()), &[][..]),
+            (None, None) => (UNASSIGNED_PANEL.to_string(), None, &[][..]),
+        };
+
+        let order = panels.len() as u32;
+        let panel = panels.entry(panel_key.clone()).or_insert_with(|| Panel {
+            display_name: panel_key,
+            description: String::new(),
+            order,
+            source_node,
+            metadata: HashMap::new(),
+            switches: vec![],
+            groups: vec![],
+        });
+
+        if groups_path.is_empty() {
+            panel.switches.push((*switch_key).clone());
+        } else {
+            insert_into_groups(&mut panel.groups, groups_path, switch_key);
+        }
+    }
+
+    panels
+}
+
+fn insert_into_groups(groups: &mut Vec<PanelGroup>, path: &[&String], switch_key: &str) {
+    let Some((node, rest)) = path.split_first() else {
+        return;
+    };
+
+    let index = match groups
+        .iter()
+        .position(|g| g.source_node.as_deref() == Some(node.as_str()))
+    {
+        Some(index) => index,
+        None => {
+            groups.push(PanelGroup {
+                name: (*node).clone(),
+                source_node: Some((*node).clone()),
+                switches: vec![],
+                groups: vec![],
+            });
+            groups.len() - 1
+        }
+    };
+    let group = &mut groups[index];
+
+    if rest.is_empty() {
+        group.switches.push(switch_key.to_string());
+    } else {
+        insert_into_groups(&mut group.groups, rest, switch_key);
+    }
+}
+
+/// Number of leading path elements every path has in common
+fn shared_prefix_len<'a>(mut paths: impl Iterator<Item = &'a [&'a String]>) -> usize {
+    let Some(first) = paths.next() else {
+        return 0;
+    };
+
+    let mut len = first.len();
+    for path in paths {
+        len = len.min(
+            first
+                .iter()
+                .zip(path.iter())
+                .take_while(|(a, b)| a == b)
+                .count(),
+        );
+    }
+
+    len
+}
@@ -1,4 +1,6 @@
+use crate::models::{CrewStation, TeleportLink};
 use crate::utils;
+use std::collections::HashMap;
 
 #[tauri::command]
 pub fn remove_teleport_zone(
@@ -10,6 +12,52 @@ pub fn remove_teleport_zone(
     // Get current config file info
     let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
 
+    let links: HashMap<String, TeleportLink> = utils::read_section(&json_data, "teleportLinks")?;
+    let mut users: Vec<&String> = links
+        .iter()
+        .filter(|(_, link)| link.references(&teleport_zone_key))
+        .map(|(name, _)| name)
+        .collect();
+    if !users.is_empty() {
+        users.sort();
+        return Err(format!(
+            "Teleport zone '{}' is still used by links: {}",
+            teleport_zone_key,
+            users
+                .iter()
+                .map(|k| k.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    let spawn: Option<String> = utils::read_section(&json_data, "defaultSpawnZone")?;
+    if spawn.as_deref() == Some(teleport_zone_key.as_str()) {
+        return Err(format!(
+            "Teleport zone '{}' is the default spawn zone",
+            teleport_zone_key
+        ));
+    }
+
+    let stations: HashMap<String, CrewStation> = utils::read_section(&json_data, "crewStations")?;
+    let mut users: Vec<&String> = stations
+        .iter()
+        .filter(|(_, station)| station.zones.contains(&teleport_zone_key))
+        .map(|(key, _)| key)
+        .collect();
+    if !users.is_empty() {
+        users.sort();
+        return Err(format!(
+            "Teleport zone '{}' is still used by crew stations: {}",
+            teleport_zone_key,
+            users
+                .iter()
+                .map(|k| k.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
     // Ensure teleportZones section exists
     let teleport_zones = utils::ensure_section_exists(&mut json_data, "teleportZones")?;
 
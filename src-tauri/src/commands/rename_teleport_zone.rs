@@ -1,16 +1,9 @@
 use super::update_teleport_zone::TeleportZoneUpdate;
+use crate::geometry;
+use crate::models::{CrewStation, TeleportLink, TeleportZone};
 use crate::utils;
-use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
-
-#[derive(Debug, Serialize, Deserialize)]
-struct TeleportZoneRename {
-    pub old_name: String,
-    pub name: String,
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
-}
+use std::collections::HashMap;
 
 #[tauri::command]
 pub fn rename_teleport_zone(
@@ -18,8 +11,6 @@ pub fn rename_teleport_zone(
     old_name: String,
     updated_zone: Json,
 ) -> Result<String, String> {
-    use serde_json::{json, Map, Value};
-
     println!(
         "Renaming teleport zone from '{}': {:?}",
         &old_name, &updated_zone
@@ -28,13 +19,20 @@ pub fn rename_teleport_zone(
     // Get current config file info
     let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
 
+    // Deserialize update submission
+    let update: TeleportZoneUpdate = utils::deserialize_json(updated_zone)?;
+
+    // The model is only needed for snapping
+    let model_path = if update.snap_to_floor {
+        Some(utils::get_model_path(&json_data)?.to_string())
+    } else {
+        None
+    };
+
     // Ensure teleportZones section exists
     let teleport_zones = utils::ensure_section_exists(&mut json_data, "teleportZones")?;
     let teleport_zones_obj = teleport_zones.as_object_mut().unwrap();
 
-    // Deserialize update submission
-    let update: TeleportZoneUpdate = utils::deserialize_json(updated_zone)?;
-
     // Check if old zone exists
     if !teleport_zones_obj.contains_key(&old_name) {
         return Err(format!("Teleport zone '{}' not found", old_name));
@@ -45,17 +43,51 @@ pub fn rename_teleport_zone(
         return Err(format!("Teleport zone '{}' already exists", update.name));
     }
 
-    // Get the old zone value
-    let _old_zone = teleport_zones_obj.remove(&old_name).unwrap();
-
-    // Create a new zone with updated properties
-    let mut new_zone = Map::new();
-    new_zone.insert("x".to_string(), json!(update.x));
-    new_zone.insert("y".to_string(), json!(update.y));
-    new_zone.insert("z".to_string(), json!(update.z));
+    // Take the old zone and apply the updated properties to it
+    let old_zone = teleport_zones_obj.remove(&old_name).unwrap();
+    let mut new_zone: TeleportZone = utils::deserialize_json(old_zone)
+        .map_err(|e| format!("Teleport zone '{}' is invalid: {}", old_name, e))?;
+    update.apply_to(&mut new_zone);
+    if let Some(model_path) = &model_path {
+        geometry::snap_to_floor(model_path, &mut new_zone)?;
+    }
 
     // Insert the new zone with the new name
-    teleport_zones_obj.insert(update.name.clone(), Value::Object(new_zone));
+    teleport_zones_obj.insert(update.name.clone(), utils::serialize_to_json(&new_zone)?);
+
+    // Point links, crew stations and the default spawn zone at the new name
+    if old_name != update.name {
+        let mut links: HashMap<String, TeleportLink> =
+            utils::read_section(&json_data, "teleportLinks")?;
+        for link in links.values_mut() {
+            for zone_name in [&mut link.from, &mut link.to] {
+                if *zone_name == old_name {
+                    *zone_name = update.name.clone();
+                }
+            }
+        }
+        if !links.is_empty() {
+            utils::write_section(&mut json_data, "teleportLinks", &links)?;
+        }
+
+        let spawn: Option<String> = utils::read_section(&json_data, "defaultSpawnZone")?;
+        if spawn.as_deref() == Some(old_name.as_str()) {
+            utils::write_section(&mut json_data, "defaultSpawnZone", &update.name)?;
+        }
+
+        let mut stations: HashMap<String, CrewStation> =
+            utils::read_section(&json_data, "crewStations")?;
+        for station in stations.values_mut() {
+            for zone_name in station.zones.iter_mut().chain(&mut station.spawn_zone) {
+                if *zone_name == old_name {
+                    *zone_name = update.name.clone();
+                }
+            }
+        }
+        if !stations.is_empty() {
+            utils::write_section(&mut json_data, "crewStations", &stations)?;
+        }
+    }
 
     // Save the updated JSON
     utils::save_json_file(&file_path, &json_data)?;
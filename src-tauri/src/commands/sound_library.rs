@@ -0,0 +1,106 @@
+use crate::models::{SoundAsset, SwitchData, ValidationSeverity};
+use crate::utils;
+use crate::validation;
+use std::collections::HashMap;
+
+const SOUND_LIBRARY_SECTION: &str = "soundLibrary";
+
+/// Add or replace a sound library entry. The audio file itself is checked by
+/// `validate_current_config`, so entries can be set up before the files are in place.
+#[tauri::command]
+pub fn set_sound_asset(
+    app_handle: tauri::AppHandle,
+    asset_name: String,
+    asset: SoundAsset,
+) -> Result<String, String> {
+    println!("Setting sound asset '{}': {:?}", &asset_name, &asset);
+
+    let errors: Vec<String> = validation::validate_sound_asset(&asset_name, &asset)
+        .into_iter()
+        .filter(|issue| issue.severity == ValidationSeverity::Error)
+        .map(|issue| issue.message)
+        .collect();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Sound asset '{}' is invalid: {}",
+            asset_name,
+            errors.join("; ")
+        ));
+    }
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    // Ensure sound library section exists
+    let library = utils::ensure_section_exists(&mut json_data, SOUND_LIBRARY_SECTION)?;
+
+    library
+        .as_object_mut()
+        .unwrap()
+        .insert(asset_name.clone(), utils::serialize_to_json(&asset)?);
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Sound asset '{}' saved successfully", asset_name))
+}
+
+/// Remove a sound library entry, refusing while any switch still uses it
+#[tauri::command]
+pub fn remove_sound_asset(
+    app_handle: tauri::AppHandle,
+    asset_name: String,
+) -> Result<String, String> {
+    println!("Removing sound asset: {}", asset_name);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+    let mut users: Vec<&String> = switches
+        .iter()
+        .filter(|(_, switch_data)| {
+            let overrides = &switch_data.sound_overrides;
+            [
+                &overrides.asset,
+                &overrides.towards_upper,
+                &overrides.towards_lower,
+            ]
+            .into_iter()
+            .flatten()
+            .chain(overrides.positions.values())
+            .chain(switch_data.sound_effect.library_asset())
+            .any(|used| used == &asset_name)
+        })
+        .map(|(key, _)| key)
+        .collect();
+    if !users.is_empty() {
+        users.sort();
+        return Err(format!(
+            "Sound asset '{}' is still used by: {}",
+            asset_name,
+            users
+                .iter()
+                .map(|k| k.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    // Ensure sound library section exists
+    let library = utils::ensure_section_exists(&mut json_data, SOUND_LIBRARY_SECTION)?;
+
+    if library
+        .as_object_mut()
+        .unwrap()
+        .remove(&asset_name)
+        .is_none()
+    {
+        return Err(format!("Sound asset '{}' not found", asset_name));
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Sound asset '{}' removed successfully", asset_name))
+}
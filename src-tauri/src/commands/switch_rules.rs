@@ -0,0 +1,209 @@
+use crate::model_scan::{self, ModelScan};
+use crate::models::{SwitchChange, SwitchData, SwitchRule, SwitchTypeDefaultsProfile};
+use crate::switch_defaults;
+use crate::switch_patch;
+use crate::switch_types;
+use crate::utils;
+use crate::validation;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as Json;
+use std::collections::HashMap;
+use tauri::AppHandle;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SwitchRulesFile {
+    #[serde(default)]
+    rules: Vec<SwitchRule>,
+}
+
+struct CompiledRule<'a> {
+    rule: &'a SwitchRule,
+    node_pattern: Option<Regex>,
+    parent_pattern: Option<Regex>,
+}
+
+#[tauri::command]
+pub fn get_switch_rules(app_handle: AppHandle) -> Result<Vec<SwitchRule>, String> {
+    load_rules(&app_handle)
+}
+
+#[tauri::command]
+pub fn set_switch_rules(app_handle: AppHandle, rules: Vec<SwitchRule>) -> Result<String, String> {
+    println!("Saving {} switch rule(s)", rules.len());
+
+    // Reject the whole set if any pattern is invalid
+    compile_rules(&rules)?;
+
+    let file_path = utils::build_workspace_file_path(&app_handle, crate::SWITCH_RULES_FILE)?;
+    utils::save_json_file(
+        &file_path,
+        &utils::serialize_to_json(&SwitchRulesFile { rules })?,
+    )?;
+
+    Ok("Switch rules saved successfully".to_string())
+}
+
+/// Dry run: report which switches the rules would change and how, without saving anything
+#[tauri::command]
+pub fn preview_switch_rules(app_handle: AppHandle) -> Result<Vec<SwitchChange>, String> {
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+    let rules = load_rules(&app_handle)?;
+
+    let profile = switch_defaults::load_profile(&app_handle)?;
+
+    let (changes, _) = plan_rule_changes(&rules, &json_data, &profile)?;
+
+    Ok(changes)
+}
+
+/// Apply the rules to every configured switch. Either every change is saved or none is.
+#[tauri::command]
+pub fn apply_switch_rules(app_handle: AppHandle) -> Result<Vec<SwitchChange>, String> {
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+    let rules = load_rules(&app_handle)?;
+
+    let profile = switch_defaults::load_profile(&app_handle)?;
+
+    let (changes, updated) = plan_rule_changes(&rules, &json_data, &profile)?;
+
+    let registry = switch_types::load_registry(&app_handle)?;
+    for (key, switch_data) in &updated {
+        validation::ensure_valid_switch(key, switch_data, &registry)?;
+    }
+
+    let switches = utils::ensure_section_exists(&mut json_data, "switches")?;
+    let switches_obj = switches.as_object_mut().unwrap();
+    for (key, switch_data) in updated {
+        switches_obj.insert(key, utils::serialize_to_json(&switch_data)?);
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    println!("Switch rules changed {} switch(es)", changes.len());
+
+    Ok(changes)
+}
+
+fn load_rules(app_handle: &AppHandle) -> Result<Vec<SwitchRule>, String> {
+    let file_path = utils::build_workspace_file_path(app_handle, crate::SWITCH_RULES_FILE)?;
+    let mut json_data = utils::load_json_file(&file_path)?;
+    if let Some(rules) = json_data.get_mut("rules").and_then(|r| r.as_array_mut()) {
+        for patch in rules
+            .iter_mut()
+            .filter_map(|r| r.get_mut("set").and_then(|s| s.as_object_mut()))
+        {
+            let switch_type = patch
+                .get("switchType")
+                .and_then(|t| t.as_str())
+                .map(str::to_string);
+            crate::migration::replace_movement_mode(patch, switch_type.as_deref());
+        }
+    }
+    let rules_file: SwitchRulesFile = utils::deserialize_json(json_data)?;
+
+    Ok(rules_file.rules)
+}
+
+fn compile_rules(rules: &[SwitchRule]) -> Result<Vec<CompiledRule<'_>>, String> {
+    rules
+        .iter()
+        .map(|rule| {
+            Ok(CompiledRule {
+                rule,
+                node_pattern: rule
+                    .node_pattern
+                    .as_deref()
+                    .map(utils::compile_node_pattern)
+                    .transpose()?,
+                parent_pattern: rule
+                    .parent_pattern
+                    .as_deref()
+                    .map(utils::compile_node_pattern)
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
+fn rule_matches(rule: &CompiledRule, switch_data: &SwitchData, scan: Option<&ModelScan>) -> bool {
+    if let Some(node_pattern) = &rule.node_pattern {
+        if !node_pattern.is_match(&switch_data.raw_node_name) {
+            return false;
+        }
+    }
+
+    if let Some(parent_pattern) = &rule.parent_pattern {
+        let Some(scan) = scan else {
+            return false;
+        };
+        if !scan
+            .ancestors(&switch_data.raw_node_name)
+            .iter()
+            .any(|ancestor| parent_pattern.is_match(ancestor))
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Run every rule in order over the configured switches. Later rules win on the same field.
+/// Returns the per-switch changes and the updated switches that differ from the file.
+fn plan_rule_changes(
+    rules: &[SwitchRule],
+    json_data: &Json,
+    profile: &SwitchTypeDefaultsProfile,
+) -> Result<(Vec<SwitchChange>, HashMap<String, SwitchData>), String> {
+    let compiled = compile_rules(rules)?;
+
+    // Only read the model when a rule actually looks at the node hierarchy.
+    // Switch detection doesn't matter for that, so no tags are needed.
+    let scan = if compiled.iter().any(|rule| rule.parent_pattern.is_some()) {
+        Some(model_scan::scan_model_file(
+            utils::get_model_path(json_data)?,
+            &[],
+        )?)
+    } else {
+        None
+    };
+
+    let switches: HashMap<String, SwitchData> = utils::read_section(json_data, "switches")?;
+
+    let mut keys: Vec<&String> = switches.keys().collect();
+    keys.sort();
+
+    let mut changes = Vec::new();
+    let mut updated = HashMap::new();
+
+    for key in keys {
+        let before = &switches[key];
+        let mut after = before.clone();
+        let mut applied_rules = Vec::new();
+
+        for rule in &compiled {
+            if rule_matches(rule, before, scan.as_ref()) {
+                switch_patch::apply_patch(&mut after, &rule.rule.set, profile);
+                applied_rules.push(rule.rule.name.clone());
+            }
+        }
+
+        let field_changes = switch_patch::diff_switch(before, &after)?;
+        if field_changes.is_empty() {
+            continue;
+        }
+
+        changes.push(SwitchChange {
+            switch_key: key.clone(),
+            rules: applied_rules,
+            changes: field_changes,
+        });
+        updated.insert(key.clone(), after);
+    }
+
+    Ok((changes, updated))
+}
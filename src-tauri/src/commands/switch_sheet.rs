@@ -0,0 +1,115 @@
+use crate::models::{SwitchData, SwitchSheetImport, SwitchUpdateOutcome, SwitchUpdateResult};
+use crate::switch_defaults;
+use crate::switch_patch;
+use crate::switch_sheet;
+use crate::switch_types;
+use crate::utils;
+use crate::validation;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Write every switch to a spreadsheet for review, as XLSX or CSV depending on the extension
+#[tauri::command]
+pub fn export_switch_sheet(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+) -> Result<String, String> {
+    println!("Exporting switches to {}", &file_path);
+
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+    let switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+
+    switch_sheet::write_switches(Path::new(&file_path), &switches)?;
+
+    Ok(format!(
+        "Exported {} switches to '{}'",
+        switches.len(),
+        file_path
+    ))
+}
+
+/// Apply the edited rows of a review spreadsheet back to the switches. Every row gets its own
+/// result listing the changed fields; rows with invalid values are rejected without blocking
+/// the rest. With `dry_run` the preview is returned without saving anything.
+#[tauri::command]
+pub fn import_switch_sheet(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+    dry_run: Option<bool>,
+) -> Result<SwitchSheetImport, String> {
+    println!("Importing switches from {}", &file_path);
+
+    let mut report = SwitchSheetImport {
+        dry_run: dry_run.unwrap_or(false),
+        ..Default::default()
+    };
+
+    // Get current config file info
+    let (_, config_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let registry = switch_types::load_registry(&app_handle)?;
+    let profile = switch_defaults::load_profile(&app_handle)?;
+    let mut switches: HashMap<String, SwitchData> = utils::read_section(&json_data, "switches")?;
+
+    for row in switch_sheet::read_switches(Path::new(&file_path))? {
+        let Some(switch_key) = row.key().map(str::to_string) else {
+            report.errors.push(format!("Row {} has no key", row.line));
+            continue;
+        };
+
+        let Some(before) = switches.get(&switch_key) else {
+            report.results.push(SwitchUpdateResult {
+                switch_key,
+                outcome: SwitchUpdateOutcome::NotFound,
+                changes: vec![],
+                error: None,
+            });
+            continue;
+        };
+
+        let checked = row.to_patch(before).and_then(|patch| {
+            let mut after = before.clone();
+            switch_patch::apply_patch(&mut after, &patch, &profile);
+            validation::ensure_valid_switch(&switch_key, &after, &registry)?;
+            Ok(after)
+        });
+        let after = match checked {
+            Ok(after) => after,
+            Err(e) => {
+                report.results.push(SwitchUpdateResult {
+                    switch_key,
+                    outcome: SwitchUpdateOutcome::Rejected,
+                    changes: vec![],
+                    error: Some(format!("Row {}: {}", row.line, e)),
+                });
+                continue;
+            }
+        };
+
+        let changes = switch_patch::diff_switch(before, &after)?;
+        let outcome = if changes.is_empty() {
+            SwitchUpdateOutcome::Unchanged
+        } else {
+            switches.insert(switch_key.clone(), after);
+            SwitchUpdateOutcome::Updated
+        };
+
+        report.results.push(SwitchUpdateResult {
+            switch_key,
+            outcome,
+            changes,
+            error: None,
+        });
+    }
+
+    if report.dry_run {
+        return Ok(report);
+    }
+
+    utils::write_section(&mut json_data, "switches", &switches)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&config_path, &json_data)?;
+
+    Ok(report)
+}
@@ -0,0 +1,83 @@
+use crate::models::{SwitchData, SwitchType, SwitchTypeDefaults, SwitchTypeDefaultsProfile};
+use crate::switch_defaults;
+use crate::switch_types;
+use crate::utils;
+use crate::validation;
+
+#[tauri::command]
+pub fn get_switch_type_defaults(
+    app_handle: tauri::AppHandle,
+) -> Result<SwitchTypeDefaultsProfile, String> {
+    switch_defaults::load_profile(&app_handle)
+}
+
+#[tauri::command]
+pub fn set_switch_type_defaults(
+    app_handle: tauri::AppHandle,
+    switch_type: SwitchType,
+    defaults: SwitchTypeDefaults,
+) -> Result<String, String> {
+    println!("Setting defaults for {:?}: {:?}", &switch_type, &defaults);
+
+    if defaults.lower_limit > defaults.upper_limit {
+        return Err("Lower limit must not be greater than upper limit".to_string());
+    }
+
+    let mut profile = switch_defaults::load_profile(&app_handle)?;
+    profile.insert(switch_type.clone(), defaults);
+    switch_defaults::save_profile(&app_handle, &profile)?;
+
+    Ok(format!(
+        "Defaults for '{}' saved successfully",
+        switch_type.id()
+    ))
+}
+
+/// Reset the selected switches to the defaults of their own switch type
+#[tauri::command]
+pub fn reset_switches_to_type_defaults(
+    app_handle: tauri::AppHandle,
+    switch_keys: Vec<String>,
+) -> Result<String, String> {
+    println!("Resetting switches to type defaults: {:?}", &switch_keys);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let profile = switch_defaults::load_profile(&app_handle)?;
+    let registry = switch_types::load_registry(&app_handle)?;
+
+    // Ensure switches section exists
+    let switches = utils::ensure_section_exists(&mut json_data, "switches")?;
+    let switches_obj = switches.as_object_mut().unwrap();
+
+    // Refuse the whole batch if any key is unknown
+    let missing: Vec<&String> = switch_keys
+        .iter()
+        .filter(|key| !switches_obj.contains_key(*key))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Switch(es) not found: {:?}", missing));
+    }
+
+    for key in &switch_keys {
+        let entry = switches_obj.get_mut(key).unwrap();
+
+        let mut switch_data: SwitchData = utils::deserialize_json(entry.clone())?;
+        let defaults = switch_defaults::defaults_for(&profile, &switch_data.switch_type);
+        switch_defaults::apply_defaults(&mut switch_data, &defaults);
+
+        // New limits can leave detents or additional axes out of range, refuse the whole batch
+        validation::ensure_valid_switch(key, &switch_data, &registry)?;
+
+        *entry = utils::serialize_to_json(&switch_data)?;
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!(
+        "{} switch(es) reset to type defaults successfully",
+        switch_keys.len()
+    ))
+}
@@ -0,0 +1,69 @@
+use crate::models::{SwitchType, SwitchTypeDefinition};
+use crate::switch_types;
+
+/// Every switch type known to the workspace, built-in ones first
+#[tauri::command]
+pub fn get_switch_types(app_handle: tauri::AppHandle) -> Result<Vec<SwitchTypeDefinition>, String> {
+    switch_types::load_registry(&app_handle)
+}
+
+/// Add a switch type to the workspace registry, or replace an existing one.
+/// Using a built-in id replaces that built-in type.
+#[tauri::command]
+pub fn set_switch_type(
+    app_handle: tauri::AppHandle,
+    definition: SwitchTypeDefinition,
+) -> Result<String, String> {
+    println!("Setting switch type: {:?}", &definition);
+
+    let registry = switch_types::load_registry(&app_handle)?;
+    let problems = switch_types::check_definition(&definition, &registry);
+    if !problems.is_empty() {
+        return Err(format!(
+            "Switch type '{}' is invalid: {}",
+            definition.id.id(),
+            problems.join("; ")
+        ));
+    }
+
+    let id = definition.id.id().to_string();
+
+    let mut custom_types = switch_types::load_custom_types(&app_handle)?;
+    match custom_types.iter_mut().find(|t| t.id == definition.id) {
+        Some(existing) => *existing = definition,
+        None => custom_types.push(definition),
+    }
+    switch_types::save_custom_types(&app_handle, custom_types)?;
+
+    Ok(format!("Switch type '{}' saved successfully", id))
+}
+
+/// Remove a workspace switch type. Removing a replaced built-in type restores the original.
+#[tauri::command]
+pub fn remove_switch_type(
+    app_handle: tauri::AppHandle,
+    switch_type: SwitchType,
+) -> Result<String, String> {
+    println!("Removing switch type: {}", switch_type.id());
+
+    let mut custom_types = switch_types::load_custom_types(&app_handle)?;
+    let count = custom_types.len();
+    custom_types.retain(|t| t.id != switch_type);
+
+    if custom_types.len() == count {
+        return Err(match switch_type {
+            SwitchType::Custom(id) => format!("Switch type '{}' not found", id),
+            builtin => format!(
+                "Switch type '{}' is built in and cannot be removed",
+                builtin.id()
+            ),
+        });
+    }
+
+    switch_types::save_custom_types(&app_handle, custom_types)?;
+
+    Ok(format!(
+        "Switch type '{}' removed successfully",
+        switch_type.id()
+    ))
+}
@@ -0,0 +1,105 @@
+use crate::models::{TeleportLink, TeleportZone, ValidationSeverity};
+use crate::utils;
+use crate::validation;
+use std::collections::HashMap;
+
+const TELEPORT_LINKS_SECTION: &str = "teleportLinks";
+const DEFAULT_SPAWN_ZONE_KEY: &str = "defaultSpawnZone";
+
+/// Add or replace a link between two existing teleport zones. Unreachable zones are left
+/// to `validate_current_config` so the graph can be built up one link at a time.
+#[tauri::command]
+pub fn set_teleport_link(
+    app_handle: tauri::AppHandle,
+    link_name: String,
+    link: TeleportLink,
+) -> Result<String, String> {
+    println!("Setting teleport link '{}': {:?}", &link_name, &link);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let zones: HashMap<String, TeleportZone> = utils::read_section(&json_data, "teleportZones")?;
+
+    let errors: Vec<String> = validation::validate_teleport_link(&link_name, &link, &zones)
+        .into_iter()
+        .filter(|issue| issue.severity == ValidationSeverity::Error)
+        .map(|issue| issue.message)
+        .collect();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Teleport link '{}' is invalid: {}",
+            link_name,
+            errors.join("; ")
+        ));
+    }
+
+    // Ensure teleportLinks section exists
+    let links = utils::ensure_section_exists(&mut json_data, TELEPORT_LINKS_SECTION)?;
+
+    links
+        .as_object_mut()
+        .unwrap()
+        .insert(link_name.clone(), utils::serialize_to_json(&link)?);
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!("Teleport link '{}' saved successfully", link_name))
+}
+
+#[tauri::command]
+pub fn remove_teleport_link(
+    app_handle: tauri::AppHandle,
+    link_name: String,
+) -> Result<String, String> {
+    println!("Removing teleport link: {}", link_name);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    // Ensure teleportLinks section exists
+    let links = utils::ensure_section_exists(&mut json_data, TELEPORT_LINKS_SECTION)?;
+
+    if links.as_object_mut().unwrap().remove(&link_name).is_none() {
+        return Err(format!("Teleport link '{}' not found", link_name));
+    }
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(format!(
+        "Teleport link '{}' removed successfully",
+        link_name
+    ))
+}
+
+/// Set the zone trainees start in, or clear it with `None`
+#[tauri::command]
+pub fn set_default_spawn_zone(
+    app_handle: tauri::AppHandle,
+    zone_name: Option<String>,
+) -> Result<String, String> {
+    println!("Setting default spawn zone: {:?}", &zone_name);
+
+    // Get current config file info
+    let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    if let Some(name) = &zone_name {
+        let zones: HashMap<String, TeleportZone> =
+            utils::read_section(&json_data, "teleportZones")?;
+        if !zones.contains_key(name) {
+            return Err(format!("Teleport zone '{}' not found", name));
+        }
+    }
+
+    utils::write_section(&mut json_data, DEFAULT_SPAWN_ZONE_KEY, &zone_name)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&file_path, &json_data)?;
+
+    Ok(match zone_name {
+        Some(name) => format!("Default spawn zone set to '{}'", name),
+        None => "Default spawn zone cleared".to_string(),
+    })
+}
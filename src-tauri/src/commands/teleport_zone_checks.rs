@@ -0,0 +1,31 @@
+use crate::geometry;
+use crate::models::{FullConfigFile, TeleportZone, ValidationIssue};
+use crate::utils;
+use crate::validation;
+use std::collections::HashMap;
+
+/// Report teleport zones that don't rest on a floor of the model
+#[tauri::command]
+pub fn check_teleport_zone_floors(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<ValidationIssue>, String> {
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+
+    let zones: HashMap<String, TeleportZone> = utils::read_section(&json_data, "teleportZones")?;
+    let geometry = geometry::load_model_geometry(utils::get_model_path(&json_data)?)?;
+
+    Ok(validation::validate_zone_floors(&zones, &geometry))
+}
+
+/// Report teleport zones that leave the aircraft, overlap or share a position, or cut into a switch
+#[tauri::command]
+pub fn validate_teleport_zones(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<ValidationIssue>, String> {
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+
+    let geometry = geometry::load_model_geometry(utils::get_model_path(&json_data)?)?;
+    let config: FullConfigFile = utils::deserialize_json(json_data)?;
+
+    Ok(validation::validate_teleport_zones(&config, &geometry))
+}
@@ -0,0 +1,150 @@
+use crate::geometry::{self, ModelGeometry};
+use crate::models::{ConflictPolicy, RenamedEntry, TeleportZone, ZoneImportReport};
+use crate::utils;
+use crate::zone_csv;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Add teleport zones from a CSV file with `name`, `x`, `y`, `z` and optional `yaw`, `shape`,
+/// `sizeX`, `sizeY`, `sizeZ`, `spawnYaw` and `snapToFloor` columns. Rows are inserted like
+/// `add_new_teleport_zone` does; `conflict_policy` decides what happens to names already in
+/// use. A name may only appear once in the file. With `dry_run` the report is returned without
+/// saving anything, otherwise any row error fails the whole import.
+#[tauri::command]
+pub fn import_teleport_zones(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+    conflict_policy: Option<ConflictPolicy>,
+    dry_run: Option<bool>,
+) -> Result<ZoneImportReport, String> {
+    println!("Importing teleport zones from {}", &file_path);
+
+    let conflict_policy = conflict_policy.unwrap_or_default();
+    let mut report = ZoneImportReport {
+        dry_run: dry_run.unwrap_or(false),
+        ..Default::default()
+    };
+
+    // Get current config file info
+    let (_, config_path, mut json_data) = utils::get_current_config(&app_handle)?;
+
+    let mut zones: HashMap<String, TeleportZone> =
+        utils::read_section(&json_data, "teleportZones")?;
+
+    let mut submissions = Vec::new();
+    for row in zone_csv::read_zones(Path::new(&file_path))? {
+        match row {
+            Ok(submission) => submissions.push(submission),
+            Err(e) => report.errors.push(e),
+        }
+    }
+
+    // Every name in the file, so renamed zones don't take a name a later row uses
+    let mut file_names: HashSet<&str> = HashSet::new();
+    let mut duplicates: HashSet<&str> = HashSet::new();
+    for submission in &submissions {
+        if !file_names.insert(&submission.teleport_zone_name) {
+            duplicates.insert(&submission.teleport_zone_name);
+        }
+    }
+    let mut duplicate_names: Vec<&str> = duplicates.iter().copied().collect();
+    duplicate_names.sort();
+    for name in duplicate_names {
+        report.errors.push(format!(
+            "Zone '{}' appears more than once in the file",
+            name
+        ));
+    }
+
+    // The model is only loaded when a row asks to be snapped
+    let mut model_geometry: Option<ModelGeometry> = None;
+
+    // Rows sharing a name are only reported, neither of them wins
+    for submission in &submissions {
+        if duplicates.contains(submission.teleport_zone_name.as_str()) {
+            continue;
+        }
+        let name = submission.teleport_zone_name.clone();
+        let mut zone = submission.to_zone();
+        if submission.snap_to_floor {
+            if model_geometry.is_none() {
+                let model_path = utils::get_model_path(&json_data)?;
+                model_geometry = Some(geometry::load_model_geometry(model_path)?);
+            }
+            if let Err(e) = model_geometry.as_ref().unwrap().snap_to_floor(&mut zone) {
+                report.errors.push(format!("Zone '{}': {}", name, e));
+                continue;
+            }
+        }
+
+        let mut existing = match zones.entry(name) {
+            Entry::Vacant(entry) => {
+                report.added.push(entry.key().clone());
+                entry.insert(zone);
+                continue;
+            }
+            Entry::Occupied(entry) => entry,
+        };
+
+        match conflict_policy {
+            ConflictPolicy::Skip => report.skipped.push(existing.key().clone()),
+            ConflictPolicy::Overwrite => {
+                report.overwritten.push(existing.key().clone());
+                existing.insert(zone);
+            }
+            ConflictPolicy::Rename => {
+                let name = existing.key().clone();
+                let free_name = (2..)
+                    .map(|n| format!("{}_{}", name, n))
+                    .find(|candidate| {
+                        !zones.contains_key(candidate) && !file_names.contains(candidate.as_str())
+                    })
+                    .unwrap();
+                report.renamed.push(RenamedEntry {
+                    from: name,
+                    to: free_name.clone(),
+                });
+                zones.insert(free_name, zone);
+            }
+        }
+    }
+
+    if report.dry_run {
+        return Ok(report);
+    }
+    if !report.errors.is_empty() {
+        return Err(format!(
+            "Nothing was imported from '{}': {}",
+            file_path,
+            report.errors.join("; ")
+        ));
+    }
+
+    utils::write_section(&mut json_data, "teleportZones", &zones)?;
+
+    // Save the updated JSON
+    utils::save_json_file(&config_path, &json_data)?;
+
+    Ok(report)
+}
+
+/// Write every teleport zone to a CSV file that `import_teleport_zones` can read back
+#[tauri::command]
+pub fn export_teleport_zones(
+    app_handle: tauri::AppHandle,
+    file_path: String,
+) -> Result<String, String> {
+    println!("Exporting teleport zones to {}", &file_path);
+
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+    let zones: HashMap<String, TeleportZone> = utils::read_section(&json_data, "teleportZones")?;
+
+    zone_csv::write_zones(Path::new(&file_path), &zones)?;
+
+    Ok(format!(
+        "Exported {} teleport zones to '{}'",
+        zones.len(),
+        file_path
+    ))
+}
@@ -0,0 +1,91 @@
+use crate::geometry;
+use crate::models::FullConfigFile;
+use crate::switch_types;
+use crate::unreal_codegen::{self, TableRow};
+use crate::unreal_export;
+use crate::utils;
+use crate::validation;
+use std::path::Path;
+
+/// Write the `USTRUCT` header and the `DeserializePlaneConfig` source matching the current
+/// config format and switch type registry. Returns the paths written.
+#[tauri::command]
+pub fn generate_unreal_code(app_handle: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let registry = switch_types::load_registry(&app_handle)?;
+
+    let files = [
+        (
+            unreal_codegen::TYPES_HEADER_FILE,
+            unreal_codegen::types_header(&registry),
+        ),
+        (
+            unreal_codegen::DESERIALIZER_FILE,
+            unreal_codegen::deserializer_source(&registry),
+        ),
+    ];
+
+    let mut written = Vec::new();
+    for (file_name, contents) in files {
+        let path = utils::build_unreal_source_file_path(&app_handle, file_name)?;
+        write_text_file(&path, &contents)?;
+        written.push(path.to_string_lossy().into_owned());
+    }
+
+    println!("Generated Unreal sources: {:?}", &written);
+
+    Ok(written)
+}
+
+/// Write the current config's switches and teleport zones as DataTable-importable CSV and
+/// JSON, in engine units and validated like `export_unreal_config`. Returns the paths written.
+#[tauri::command]
+pub fn export_unreal_data_tables(app_handle: tauri::AppHandle) -> Result<Vec<String>, String> {
+    let (current_file, _, json_data) = utils::get_current_config(&app_handle)?;
+    let geometry = geometry::load_model_geometry(utils::get_model_path(&json_data)?)?;
+    let config: FullConfigFile = utils::deserialize_json(json_data)?;
+    let registry = switch_types::load_registry(&app_handle)?;
+
+    validation::ensure_valid_teleport_zones(&config, &geometry)?;
+
+    let export = unreal_export::build_export(&config)?;
+    let tables = unreal_codegen::data_tables(&export, &registry);
+
+    let stem = Path::new(&current_file)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or(current_file.clone());
+
+    let mut written = Vec::new();
+    for (table, rows) in [
+        ("Switches", &tables.switches),
+        ("TeleportZones", &tables.teleport_zones),
+    ] {
+        written.extend(write_table(&app_handle, &stem, table, rows)?);
+    }
+
+    println!("Exported DataTables for '{}': {:?}", current_file, &written);
+
+    Ok(written)
+}
+
+fn write_table(
+    app_handle: &tauri::AppHandle,
+    stem: &str,
+    table: &str,
+    rows: &[TableRow],
+) -> Result<Vec<String>, String> {
+    let csv_path = utils::build_export_file_path(app_handle, &format!("{}_{}.csv", stem, table))?;
+    write_text_file(&csv_path, &unreal_codegen::table_csv(rows)?)?;
+
+    let json_path = utils::build_export_file_path(app_handle, &format!("{}_{}.json", stem, table))?;
+    utils::save_json_file(&json_path, &unreal_codegen::table_json(rows))?;
+
+    Ok(vec![
+        csv_path.to_string_lossy().into_owned(),
+        json_path.to_string_lossy().into_owned(),
+    ])
+}
+
+fn write_text_file(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write {:?}: {}", path, e))
+}
@@ -1,3 +1,5 @@
+use crate::geometry;
+use crate::models::{TeleportZone, TeleportZoneProperties, Vec3};
 use crate::utils;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as Json;
@@ -8,40 +10,61 @@ pub struct TeleportZoneUpdate {
     pub x: f64,
     pub y: f64,
     pub z: f64,
+    #[serde(flatten)]
+    pub properties: TeleportZoneProperties,
+    #[serde(default)]
+    pub snap_to_floor: bool,
 }
 
-// Regular update (only coordinates)
+impl TeleportZoneUpdate {
+    /// Move a zone and apply any other properties the update carries
+    pub fn apply_to(&self, zone: &mut TeleportZone) {
+        zone.position = Vec3 {
+            x: self.x as f32,
+            y: self.y as f32,
+            z: self.z as f32,
+        };
+        self.properties.apply_to(zone);
+    }
+}
+
+// Regular update (coordinates, plus any zone properties that are set)
 #[tauri::command]
 pub fn update_teleport_zone(
     app_handle: tauri::AppHandle,
     updated_zone: Json,
 ) -> Result<String, String> {
-    use serde_json::json;
-
     println!("Updating teleport zone: {:?}", &updated_zone);
 
     // Get current config file info
     let (_, file_path, mut json_data) = utils::get_current_config(&app_handle)?;
 
-    // Ensure teleportZones section exists
-    let teleport_zones = utils::ensure_section_exists(&mut json_data, "teleportZones")?;
-
     // Deserialize update submission
     let update: TeleportZoneUpdate = utils::deserialize_json(updated_zone)?;
 
+    // The model is only needed for snapping
+    let model_path = if update.snap_to_floor {
+        Some(utils::get_model_path(&json_data)?.to_string())
+    } else {
+        None
+    };
+
+    // Ensure teleportZones section exists
+    let teleport_zones = utils::ensure_section_exists(&mut json_data, "teleportZones")?;
+
     // Update the teleport zone
     if let Some(zone) = teleport_zones
         .as_object_mut()
         .unwrap()
         .get_mut(&update.name)
     {
-        if let Some(zone_obj) = zone.as_object_mut() {
-            zone_obj["x"] = json!(update.x);
-            zone_obj["y"] = json!(update.y);
-            zone_obj["z"] = json!(update.z);
-        } else {
-            return Err(format!("Teleport zone '{}' is not an object", update.name));
+        let mut zone_data: TeleportZone = utils::deserialize_json(zone.clone())
+            .map_err(|e| format!("Teleport zone '{}' is invalid: {}", update.name, e))?;
+        update.apply_to(&mut zone_data);
+        if let Some(model_path) = &model_path {
+            geometry::snap_to_floor(model_path, &mut zone_data)?;
         }
+        *zone = utils::serialize_to_json(&zone_data)?;
     } else {
         return Err(format!("Teleport zone '{}' not found", update.name));
     }
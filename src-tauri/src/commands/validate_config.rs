@@ -0,0 +1,21 @@
+use crate::models::{FullConfigFile, ValidationIssue};
+use crate::utils;
+
+/// Run every validation check over the current config file, including the ones that
+/// look at files on disk
+#[tauri::command]
+pub fn validate_current_config(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<ValidationIssue>, String> {
+    let (_, _, json_data) = utils::get_current_config(&app_handle)?;
+    let config: FullConfigFile = utils::deserialize_json(json_data)?;
+
+    let mut issues = crate::validation::validate_config(&config);
+    issues.extend(crate::validation::validate_sound_files(&config));
+    issues.extend(crate::validation::validate_switch_types(
+        &config,
+        &crate::switch_types::load_registry(&app_handle)?,
+    ));
+
+    Ok(issues)
+}
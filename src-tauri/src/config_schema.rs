@@ -0,0 +1,37 @@
+use crate::migration::CURRENT_SCHEMA_VERSION;
+use crate::models::FullConfigFile;
+use crate::utils;
+use schemars::schema::Schema;
+use serde_json::{json, Value as Json};
+use std::path::Path;
+
+/// JSON Schema of the plane config format, derived from `FullConfigFile`. Field descriptions
+/// come from the model doc comments.
+pub fn config_schema() -> Result<Json, String> {
+    let mut schema = schemars::schema_for!(FullConfigFile);
+
+    let metadata = schema.schema.metadata();
+    metadata.title = Some("Plane config".to_string());
+    metadata.description = Some(
+        "Switches, teleport zones and related data of one aircraft, as stored in plane_configs"
+            .to_string(),
+    );
+
+    // Older versions are migrated on load, the schema only describes the current one. The
+    // serde default is the legacy version, so it goes too.
+    if let Some(Schema::Object(version)) =
+        schema.schema.object().properties.get_mut("schemaVersion")
+    {
+        version.const_value = Some(json!(CURRENT_SCHEMA_VERSION));
+        version.metadata().default = None;
+    }
+
+    utils::serialize_to_json(&schema)
+}
+
+/// Write the schema to `path`, returning it
+pub fn write_config_schema(path: &Path) -> Result<Json, String> {
+    let schema = config_schema()?;
+    utils::save_json_file(path, &schema)?;
+    Ok(schema)
+}
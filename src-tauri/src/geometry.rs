@@ -0,0 +1,418 @@
+use crate::models::{TeleportZone, TeleportZoneShape, Vec3};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Surfaces whose normal points up at least this much (about 45°) count as floor
+const FLOOR_MIN_NORMAL_Z: f32 = 0.7;
+
+/// How far a zone may sit inside a floor and still find it below
+pub const FLOOR_STEP_UP: f32 = 0.3;
+
+/// Corners used to approximate a cylinder's footprint
+const CYLINDER_SEGMENTS: usize = 16;
+
+type Matrix = [[f32; 4]; 4];
+
+const IDENTITY: Matrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+/// An axis-aligned box in config space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Bounds {
+    fn around(point: Vec3) -> Self {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    fn include(&mut self, point: Vec3) {
+        self.min.x = self.min.x.min(point.x);
+        self.min.y = self.min.y.min(point.y);
+        self.min.z = self.min.z.min(point.z);
+        self.max.x = self.max.x.max(point.x);
+        self.max.y = self.max.y.max(point.y);
+        self.max.z = self.max.z.max(point.z);
+    }
+
+    fn merge(bounds: Option<Bounds>, other: Option<Bounds>) -> Option<Bounds> {
+        match (bounds, other) {
+            (Some(mut bounds), Some(other)) => {
+                bounds.include(other.min);
+                bounds.include(other.max);
+                Some(bounds)
+            }
+            (bounds, other) => bounds.or(other),
+        }
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn intersects(&self, other: &Bounds) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+}
+
+/// The space a teleport zone takes up: a convex footprint extruded upwards from its floor
+pub struct ZoneVolume {
+    footprint: Vec<[f32; 2]>,
+    bottom: f32,
+    top: f32,
+}
+
+impl ZoneVolume {
+    pub fn of(zone: &TeleportZone) -> Self {
+        let (half_x, half_y, height) = match zone.shape {
+            TeleportZoneShape::Box => (
+                zone.dimensions.x / 2.0,
+                zone.dimensions.y / 2.0,
+                zone.dimensions.z,
+            ),
+            TeleportZoneShape::Cylinder => (zone.dimensions.x / 2.0, 0.0, zone.dimensions.z),
+            TeleportZoneShape::Point => (0.0, 0.0, 0.0),
+        };
+
+        let local: Vec<[f32; 2]> = match zone.shape {
+            TeleportZoneShape::Box => vec![
+                [-half_x, -half_y],
+                [half_x, -half_y],
+                [half_x, half_y],
+                [-half_x, half_y],
+            ],
+            TeleportZoneShape::Cylinder => (0..CYLINDER_SEGMENTS)
+                .map(|i| {
+                    let angle = i as f32 / CYLINDER_SEGMENTS as f32 * std::f32::consts::TAU;
+                    [half_x * angle.cos(), half_x * angle.sin()]
+                })
+                .collect(),
+            TeleportZoneShape::Point => vec![[0.0, 0.0]],
+        };
+
+        let (sin, cos) = zone.yaw.to_radians().sin_cos();
+        let footprint = local
+            .into_iter()
+            .map(|[x, y]| {
+                [
+                    zone.position.x + x * cos - y * sin,
+                    zone.position.y + x * sin + y * cos,
+                ]
+            })
+            .collect();
+
+        ZoneVolume {
+            footprint,
+            bottom: zone.position.z,
+            top: zone.position.z + height.max(0.0),
+        }
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        let mut bounds = Bounds::around(Vec3 {
+            x: self.footprint[0][0],
+            y: self.footprint[0][1],
+            z: self.bottom,
+        });
+        for [x, y] in &self.footprint {
+            bounds.include(Vec3 {
+                x: *x,
+                y: *y,
+                z: self.top,
+            });
+        }
+        bounds
+    }
+
+    /// Whether two volumes share any space, touching faces included
+    pub fn overlaps(&self, other: &ZoneVolume) -> bool {
+        if self.top < other.bottom || other.top < self.bottom {
+            return false;
+        }
+
+        // Separating axis test over the edge normals of both footprints, plus the
+        // world axes so that point zones, which have no edges, are still compared
+        [&self.footprint, &other.footprint]
+            .into_iter()
+            .flat_map(|polygon| edge_normals(polygon))
+            .chain([[1.0, 0.0], [0.0, 1.0]])
+            .all(|axis| {
+                let (a_min, a_max) = project(&self.footprint, axis);
+                let (b_min, b_max) = project(&other.footprint, axis);
+                a_min <= b_max && b_min <= a_max
+            })
+    }
+}
+
+fn edge_normals(polygon: &[[f32; 2]]) -> Vec<[f32; 2]> {
+    (0..polygon.len())
+        .filter_map(|i| {
+            let [ax, ay] = polygon[i];
+            let [bx, by] = polygon[(i + 1) % polygon.len()];
+            let normal = [ay - by, bx - ax];
+            (normal != [0.0, 0.0]).then_some(normal)
+        })
+        .collect()
+}
+
+fn project(polygon: &[[f32; 2]], axis: [f32; 2]) -> (f32, f32) {
+    polygon
+        .iter()
+        .map(|[x, y]| x * axis[0] + y * axis[1])
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+/// The triangles of a model in config space (z up), for geometry queries
+pub struct ModelGeometry {
+    triangles: Vec<[Vec3; 3]>,
+    pub bounds: Option<Bounds>, // Everything in the model, None when it has no meshes
+    pub node_bounds: HashMap<String, Bounds>, // Named node -> its meshes and those below it
+}
+
+impl ModelGeometry {
+    /// Height of the highest floor at (x, y) that is not above `from_z`, as seen by a ray
+    /// cast straight down from there
+    pub fn floor_below(&self, x: f32, y: f32, from_z: f32) -> Option<f32> {
+        self.triangles
+            .iter()
+            .filter(|triangle| is_floor(triangle))
+            .filter_map(|triangle| height_at(triangle, x, y))
+            .filter(|z| *z <= from_z)
+            .max_by(|a, b| a.total_cmp(b))
+    }
+
+    /// The floor a zone at `position` stands on, allowing it to be sunk in slightly
+    pub fn floor_under(&self, position: Vec3) -> Option<f32> {
+        self.floor_below(position.x, position.y, position.z + FLOOR_STEP_UP)
+    }
+
+    /// Move a zone down, or up by at most a step, onto the floor below it
+    pub fn snap_to_floor(&self, zone: &mut TeleportZone) -> Result<(), String> {
+        let floor = self.floor_under(zone.position).ok_or_else(|| {
+            format!(
+                "No floor found below ({}, {}, {})",
+                zone.position.x, zone.position.y, zone.position.z
+            )
+        })?;
+        zone.position.z = floor;
+
+        Ok(())
+    }
+}
+
+/// Read a GLB/glTF file and collect its triangles in world space
+pub fn load_model_geometry(model_path: &str) -> Result<ModelGeometry, String> {
+    let data = std::fs::read(model_path)
+        .map_err(|e| format!("Failed to read model '{}': {}", model_path, e))?;
+    let gltf = gltf::Gltf::from_slice(&data).map_err(|e| e.to_string())?;
+
+    // External buffers are looked up next to the model
+    let buffers = gltf::import_buffers(&gltf.document, Path::new(model_path).parent(), gltf.blob)
+        .map_err(|e| format!("Failed to load buffers of '{}': {}", model_path, e))?;
+
+    let mut geometry = ModelGeometry {
+        triangles: Vec::new(),
+        bounds: None,
+        node_bounds: HashMap::new(),
+    };
+
+    for scene in gltf.document.scenes() {
+        for node in scene.nodes() {
+            let bounds = collect_node(node, &IDENTITY, &buffers, &mut geometry);
+            geometry.bounds = Bounds::merge(geometry.bounds, bounds);
+        }
+    }
+
+    Ok(geometry)
+}
+
+/// Add a node's triangles and its children's, returning the bounds of all of them
+fn collect_node(
+    node: gltf::Node,
+    parent_transform: &Matrix,
+    buffers: &[gltf::buffer::Data],
+    geometry: &mut ModelGeometry,
+) -> Option<Bounds> {
+    let transform = multiply(parent_transform, &node.transform().matrix());
+    let mut bounds = None;
+
+    if let Some(mesh) = node.mesh() {
+        for primitive in mesh.primitives() {
+            if primitive.mode() != gltf::mesh::Mode::Triangles {
+                continue;
+            }
+
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let Some(positions) = reader.read_positions() else {
+                continue;
+            };
+            let vertices: Vec<Vec3> = positions
+                .map(|p| to_config_space(transform_point(&transform, p)))
+                .collect();
+            let indices: Vec<u32> = match reader.read_indices() {
+                Some(indices) => indices.into_u32().collect(),
+                None => (0..vertices.len() as u32).collect(),
+            };
+
+            for corner in indices.chunks_exact(3) {
+                // Skip triangles pointing past the vertex list rather than trusting the file
+                let [Some(a), Some(b), Some(c)] =
+                    [corner[0], corner[1], corner[2]].map(|i| vertices.get(i as usize).copied())
+                else {
+                    continue;
+                };
+                let triangle = [a, b, c];
+                for point in triangle {
+                    bounds = Bounds::merge(bounds, Some(Bounds::around(point)));
+                }
+                geometry.triangles.push(triangle);
+            }
+        }
+    }
+
+    for child in node.children() {
+        let child_bounds = collect_node(child, &transform, buffers, geometry);
+        bounds = Bounds::merge(bounds, child_bounds);
+    }
+
+    if let (Some(name), Some(bounds)) = (node.name(), bounds) {
+        geometry.node_bounds.insert(name.to_string(), bounds);
+    }
+
+    bounds
+}
+
+/// glTF is y-up while configs are z-up like the engine, matching the editor's axis swap
+fn to_config_space(point: [f32; 3]) -> Vec3 {
+    Vec3 {
+        x: point[0],
+        y: -point[2],
+        z: point[1],
+    }
+}
+
+// Matrices are column-major, as glTF stores them
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = [[0.0; 4]; 4];
+    for (column, b_column) in b.iter().enumerate() {
+        for row in 0..4 {
+            result[column][row] = (0..4).map(|k| a[k][row] * b_column[k]).sum();
+        }
+    }
+    result
+}
+
+fn transform_point(m: &Matrix, p: [f32; 3]) -> [f32; 3] {
+    let mut result = [0.0; 3];
+    for (row, value) in result.iter_mut().enumerate() {
+        *value = m[0][row] * p[0] + m[1][row] * p[1] + m[2][row] * p[2] + m[3][row];
+    }
+    result
+}
+
+fn is_floor([a, b, c]: &[Vec3; 3]) -> bool {
+    let (ab, ac) = (sub(*b, *a), sub(*c, *a));
+    let normal = Vec3 {
+        x: ab.y * ac.z - ab.z * ac.y,
+        y: ab.z * ac.x - ab.x * ac.z,
+        z: ab.x * ac.y - ab.y * ac.x,
+    };
+    let length = (normal.x * normal.x + normal.y * normal.y + normal.z * normal.z).sqrt();
+
+    // Meshes aren't reliably wound, so a floor may face either way
+    length > 0.0 && normal.z.abs() / length >= FLOOR_MIN_NORMAL_Z
+}
+
+/// Height of the triangle where a vertical line through (x, y) crosses it
+fn height_at([a, b, c]: &[Vec3; 3], x: f32, y: f32) -> Option<f32> {
+    let denominator = (b.y - c.y) * (a.x - c.x) + (c.x - b.x) * (a.y - c.y);
+    if denominator.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let u = ((b.y - c.y) * (x - c.x) + (c.x - b.x) * (y - c.y)) / denominator;
+    let v = ((c.y - a.y) * (x - c.x) + (a.x - c.x) * (y - c.y)) / denominator;
+    let w = 1.0 - u - v;
+
+    (u >= 0.0 && v >= 0.0 && w >= 0.0).then_some(u * a.z + v * b.z + w * c.z)
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    Vec3 {
+        x: a.x - b.x,
+        y: a.y - b.y,
+        z: a.z - b.z,
+    }
+}
+
+/// Move a zone onto the floor below it in the given model
+pub fn snap_to_floor(model_path: &str, zone: &mut TeleportZone) -> Result<(), String> {
+    load_model_geometry(model_path)?.snap_to_floor(zone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DEFAULT_ZONE_DIMENSIONS;
+
+    fn zone(x: f32, y: f32, yaw: f32, shape: TeleportZoneShape) -> ZoneVolume {
+        ZoneVolume::of(&TeleportZone {
+            yaw,
+            shape,
+            ..TeleportZone::at(Vec3 { x, y, z: 0.0 })
+        })
+    }
+
+    #[test]
+    fn boxes_overlap_only_when_they_share_space() {
+        let origin = zone(0.0, 0.0, 0.0, TeleportZoneShape::Box);
+        assert!(origin.overlaps(&zone(0.5, 0.5, 0.0, TeleportZoneShape::Box)));
+        // Touching faces count
+        assert!(origin.overlaps(&zone(1.0, 0.0, 0.0, TeleportZoneShape::Box)));
+        assert!(!origin.overlaps(&zone(1.5, 0.0, 0.0, TeleportZoneShape::Box)));
+    }
+
+    #[test]
+    fn rotation_is_taken_into_account() {
+        let origin = zone(0.0, 0.0, 0.0, TeleportZoneShape::Box);
+        // Apart when aligned, but a 45 degree turn reaches across the gap
+        assert!(!origin.overlaps(&zone(1.2, 0.0, 0.0, TeleportZoneShape::Box)));
+        assert!(origin.overlaps(&zone(1.2, 0.0, 45.0, TeleportZoneShape::Box)));
+    }
+
+    #[test]
+    fn zones_at_different_heights_do_not_overlap() {
+        let origin = zone(0.0, 0.0, 0.0, TeleportZoneShape::Box);
+        let above = ZoneVolume::of(&TeleportZone::at(Vec3 {
+            x: 0.0,
+            y: 0.0,
+            z: DEFAULT_ZONE_DIMENSIONS.z + 0.1,
+        }));
+        assert!(!origin.overlaps(&above));
+    }
+
+    #[test]
+    fn points_and_cylinders() {
+        let cylinder = zone(0.0, 0.0, 0.0, TeleportZoneShape::Cylinder);
+        assert!(cylinder.overlaps(&zone(0.3, 0.0, 0.0, TeleportZoneShape::Point)));
+        assert!(!cylinder.overlaps(&zone(0.45, 0.45, 0.0, TeleportZoneShape::Point)));
+    }
+}
@@ -4,8 +4,21 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod commands;
+mod config_schema;
+mod geometry;
+mod migration;
+mod mirroring;
+mod model_scan;
 mod models;
+mod switch_defaults;
+mod switch_patch;
+mod switch_sheet;
+mod switch_types;
+mod unreal_codegen;
+mod unreal_export;
 mod utils;
+mod validation;
+mod zone_csv;
 
 use commands::*;
 use tauri::Manager;
@@ -13,6 +26,12 @@ use tauri::Manager;
 use std::sync::Mutex;
 
 pub const OUTPUT_FOLDER_PATH: &str = "plane_configs";
+pub const CONFIG_SCHEMA_FILE: &str = "plane_config.schema.json";
+pub const SWITCH_TYPE_DEFAULTS_FILE: &str = "switch_type_defaults.json";
+pub const SWITCH_RULES_FILE: &str = "switch_rules.json";
+pub const SWITCH_TYPES_FILE: &str = "switch_types.json";
+pub const UNREAL_EXPORT_FOLDER_PATH: &str = "unreal_exports";
+pub const UNREAL_SOURCE_FOLDER_PATH: &str = "unreal_exports/Source";
 
 pub struct AppData {
     current_json_file: String,
@@ -28,6 +47,12 @@ fn setup_plane_config_folder(app: &mut tauri::App) {
 
     std::fs::create_dir_all(&plane_config_folder_path)
         .expect("Failed to create app data directory");
+
+    // Keep the published schema in step with this version of the app
+    let schema_path = plane_config_folder_path.join(CONFIG_SCHEMA_FILE);
+    if let Err(e) = config_schema::write_config_schema(&schema_path) {
+        println!("Failed to write the config schema: {}", e);
+    }
 }
 
 fn main() {
@@ -48,7 +73,51 @@ fn main() {
             create_new_config_file,
             get_current_config_file_contents,
             open_file,
-            load_plane_model_data
+            load_plane_model_data,
+            classify_model_nodes,
+            set_node_classification,
+            set_node_classification_rules,
+            get_switch_type_defaults,
+            set_switch_type_defaults,
+            reset_switches_to_type_defaults,
+            get_switch_rules,
+            set_switch_rules,
+            preview_switch_rules,
+            apply_switch_rules,
+            bulk_update_switches,
+            set_panel,
+            remove_panel,
+            derive_panels_from_model,
+            validate_current_config,
+            set_interlock,
+            remove_interlock,
+            set_composite_control,
+            remove_composite_control,
+            detect_composite_controls,
+            export_unreal_config,
+            set_sound_asset,
+            remove_sound_asset,
+            get_switch_types,
+            set_switch_type,
+            remove_switch_type,
+            check_teleport_zone_floors,
+            validate_teleport_zones,
+            set_teleport_link,
+            remove_teleport_link,
+            set_default_spawn_zone,
+            set_crew_station,
+            remove_crew_station,
+            get_station_switches,
+            mirror_teleport_zones,
+            array_teleport_zone,
+            mirror_switches,
+            import_teleport_zones,
+            export_teleport_zones,
+            export_switch_sheet,
+            import_switch_sheet,
+            generate_unreal_code,
+            export_unreal_data_tables,
+            get_config_schema
         ])
         .setup(|app| {
             setup_plane_config_folder(app);
@@ -0,0 +1,293 @@
+use crate::models::{TeleportZoneShape, DEFAULT_ZONE_DIMENSIONS};
+use crate::utils;
+use serde_json::{json, Map, Value as Json};
+use std::path::Path;
+
+/// Schema version written by this build. Bump it and add a step to `migrate_config`
+/// whenever the config file layout changes.
+pub const CURRENT_SCHEMA_VERSION: u64 = 4;
+
+const SCHEMA_VERSION_KEY: &str = "schemaVersion";
+
+/// Bring a config document up to the current schema in place.
+/// Returns whether anything changed. Files without a version are treated as version 1.
+pub fn migrate_config(json_data: &mut Json) -> Result<bool, String> {
+    let root = json_data
+        .as_object()
+        .ok_or_else(|| "Invalid JSON structure: expected an object at the root".to_string())?;
+
+    // Missing or empty files are created fresh, there is nothing to migrate
+    if root.is_empty() {
+        return Ok(false);
+    }
+
+    let version = root
+        .get(SCHEMA_VERSION_KEY)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1);
+
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(format!(
+            "Config file uses schema version {}, this build only understands up to {}",
+            version, CURRENT_SCHEMA_VERSION
+        ));
+    }
+    if version == CURRENT_SCHEMA_VERSION {
+        return Ok(false);
+    }
+
+    for from_version in version..CURRENT_SCHEMA_VERSION {
+        println!(
+            "Migrating config from schema {} to {}",
+            from_version,
+            from_version + 1
+        );
+        match from_version {
+            1 => v1_to_v2(json_data)?,
+            2 => v2_to_v3(json_data)?,
+            3 => v3_to_v4(json_data)?,
+            _ => unreachable!("no migration step from schema {}", from_version),
+        }
+    }
+
+    json_data.as_object_mut().unwrap().insert(
+        SCHEMA_VERSION_KEY.to_string(),
+        json!(CURRENT_SCHEMA_VERSION),
+    );
+
+    Ok(true)
+}
+
+/// Migrate a config file on disk, saving it only if something changed
+pub fn migrate_config_file(file_path: &Path) -> Result<bool, String> {
+    let mut json_data = utils::load_json_file(file_path)?;
+
+    let migrated = migrate_config(&mut json_data)?;
+    if migrated {
+        utils::save_json_file(file_path, &json_data)?;
+    }
+
+    Ok(migrated)
+}
+
+/// v2: switches list their extra degrees of freedom and axis couplings explicitly.
+/// The flat limit fields stay as the primary axis.
+fn v1_to_v2(json_data: &mut Json) -> Result<(), String> {
+    for_each_switch(json_data, |switch| {
+        switch.entry("additionalAxes").or_insert(json!([]));
+        switch.entry("axisCouplings").or_insert(json!([]));
+        Ok(())
+    })
+}
+
+/// v3: the `movementMode` flag becomes `discrete`, and the motion kind and units are made
+/// explicit. Applied to the primary axis and every additional axis. Switches whose type gives
+/// no hint about their motion are marked unreviewed.
+fn v2_to_v3(json_data: &mut Json) -> Result<(), String> {
+    for_each_switch(json_data, |switch| {
+        let switch_type = switch
+            .get("switchType")
+            .and_then(|t| t.as_str())
+            .map(str::to_string);
+        let mut known = replace_movement_mode(switch, switch_type.as_deref());
+
+        if let Some(axes) = switch
+            .get_mut("additionalAxes")
+            .and_then(|a| a.as_array_mut())
+        {
+            for axis in axes.iter_mut().filter_map(|a| a.as_object_mut()) {
+                known &= replace_movement_mode(axis, switch_type.as_deref());
+            }
+        }
+
+        if !known {
+            switch.insert("reviewStatus".to_string(), json!("unreviewed"));
+        }
+        Ok(())
+    })
+}
+
+/// Motion kind and units a control of this type was configured in before they were explicit
+fn legacy_motion(switch_type: &str) -> Option<(&'static str, &'static str)> {
+    match switch_type {
+        "button" => Some(("translate", "m")),
+        "lever" | "dial" | "throttle" => Some(("rotate", "degrees")),
+        _ => None,
+    }
+}
+
+/// Swap a legacy `movementMode` flag, which marked discrete controls, for `discrete`, and fill
+/// in `motion` and `units` from the switch type. Unknown types are assumed to rotate in
+/// degrees and `false` is returned so the caller can flag them. Without a type (a rule patch
+/// that doesn't set one) only the flag is carried over.
+/// Also used for workspace files (type defaults, rules) that carry the same fields.
+pub fn replace_movement_mode(entry: &mut Map<String, Json>, switch_type: Option<&str>) -> bool {
+    let Some(movement_mode) = entry.remove("movementMode") else {
+        return true;
+    };
+
+    // Patches store unset fields as null, those have nothing to carry over
+    let Some(discrete) = movement_mode.as_bool() else {
+        return true;
+    };
+    entry.entry("discrete").or_insert(json!(discrete));
+
+    let Some(switch_type) = switch_type else {
+        return true;
+    };
+    let (motion, units, known) = match legacy_motion(switch_type) {
+        Some((motion, units)) => (motion, units, true),
+        None => ("rotate", "degrees", false),
+    };
+    entry.entry("motion").or_insert(json!(motion));
+    entry.entry("units").or_insert(json!(units));
+    known
+}
+
+/// v4: teleport zones move from a bare point to a positioned, rotated volume.
+/// Old zones become default-sized boxes standing on their point.
+fn v3_to_v4(json_data: &mut Json) -> Result<(), String> {
+    let Some(zones) = json_data
+        .get_mut("teleportZones")
+        .and_then(|z| z.as_object_mut())
+    else {
+        return Ok(());
+    };
+
+    for (key, zone) in zones.iter_mut() {
+        let zone = zone.as_object_mut().ok_or_else(|| {
+            format!(
+                "Invalid JSON structure: teleport zone '{}' is not an object",
+                key
+            )
+        })?;
+        if zone.contains_key("position") {
+            continue;
+        }
+
+        let mut position = Map::new();
+        for axis in ["x", "y", "z"] {
+            position.insert(axis.to_string(), zone.remove(axis).unwrap_or(json!(0.0)));
+        }
+        zone.insert("position".to_string(), Json::Object(position));
+        zone.entry("yaw").or_insert(json!(0.0));
+        zone.entry("shape").or_insert(json!(TeleportZoneShape::Box));
+        zone.entry("dimensions")
+            .or_insert(json!(DEFAULT_ZONE_DIMENSIONS));
+    }
+
+    Ok(())
+}
+
+fn for_each_switch(
+    json_data: &mut Json,
+    mut migrate: impl FnMut(&mut Map<String, Json>) -> Result<(), String>,
+) -> Result<(), String> {
+    let Some(switches) = json_data
+        .get_mut("switches")
+        .and_then(|s| s.as_object_mut())
+    else {
+        return Ok(());
+    };
+
+    for (key, switch) in switches.iter_mut() {
+        let switch = switch
+            .as_object_mut()
+            .ok_or_else(|| format!("Invalid JSON structure: switch '{}' is not an object", key))?;
+        migrate(switch)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Entries as they appear in public/b52.json, which predates schema versions
+    fn b52_config() -> Json {
+        json!({
+            "modelPath": "BetaFlixLightAircraft.glb",
+            "planeName": "b-52",
+            "teleportZones": { "Zone1": { "x": 0, "y": 1, "z": 2 } },
+            "switches": {
+                "CenterDial1": {
+                    "bleedMargins": 0.5,
+                    "defaultPosition": 0.0,
+                    "lowerLimit": 0.0,
+                    "momentarySwitch": false,
+                    "movementAxis": "Y",
+                    "movementMode": false,
+                    "soundEffect": "dialSound",
+                    "switchDescription": "Test test  test",
+                    "switchType": "dial",
+                    "upperLimit": 90.0
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn migrates_unversioned_files_to_the_current_schema() {
+        let mut config = b52_config();
+        assert!(migrate_config(&mut config).unwrap());
+        assert_eq!(config[SCHEMA_VERSION_KEY], json!(CURRENT_SCHEMA_VERSION));
+
+        // The flat limits stay as the primary axis
+        let dial = &config["switches"]["CenterDial1"];
+        assert_eq!(dial["additionalAxes"], json!([]));
+        assert_eq!(dial["axisCouplings"], json!([]));
+        assert_eq!(dial["upperLimit"], json!(90.0));
+
+        // Already current, nothing more to do
+        assert!(!migrate_config(&mut config).unwrap());
+    }
+
+    #[test]
+    fn derives_motion_from_the_switch_type() {
+        let mut config = b52_config();
+        config["switches"]["Start"] = json!({ "switchType": "button", "movementMode": true });
+        migrate_config(&mut config).unwrap();
+
+        let dial = &config["switches"]["CenterDial1"];
+        assert_eq!(dial["motion"], json!("rotate"));
+        assert_eq!(dial["units"], json!("degrees"));
+        assert_eq!(dial["discrete"], json!(false));
+        assert!(dial.get("movementMode").is_none());
+        assert!(dial.get("reviewStatus").is_none());
+
+        let button = &config["switches"]["Start"];
+        assert_eq!(button["motion"], json!("translate"));
+        assert_eq!(button["units"], json!("m"));
+        assert_eq!(button["discrete"], json!(true));
+    }
+
+    #[test]
+    fn unknown_switch_types_are_marked_unreviewed() {
+        let mut config = b52_config();
+        config["switches"]["CenterDial1"]["switchType"] = json!("knob");
+        migrate_config(&mut config).unwrap();
+
+        let knob = &config["switches"]["CenterDial1"];
+        assert_eq!(knob["motion"], json!("rotate"));
+        assert_eq!(knob["reviewStatus"], json!("unreviewed"));
+    }
+
+    #[test]
+    fn point_zones_become_default_boxes() {
+        let mut config = b52_config();
+        migrate_config(&mut config).unwrap();
+
+        let zone = &config["teleportZones"]["Zone1"];
+        assert_eq!(zone["position"], json!({ "x": 0, "y": 1, "z": 2 }));
+        assert_eq!(zone["shape"], json!(TeleportZoneShape::Box));
+        assert_eq!(zone["dimensions"], json!(DEFAULT_ZONE_DIMENSIONS));
+        assert!(zone.get("x").is_none());
+    }
+
+    #[test]
+    fn rejects_newer_schema() {
+        let mut config = json!({ "schemaVersion": CURRENT_SCHEMA_VERSION + 1, "switches": {} });
+        assert!(migrate_config(&mut config).is_err());
+    }
+}
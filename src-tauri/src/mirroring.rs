@@ -0,0 +1,132 @@
+use crate::models::{MirrorAxis, MirrorPlane, NameSwap, TeleportZone, Vec3, ZoneArray};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Rewrites names to their mirrored counterpart by swapping every fragment in one pass,
+/// so `LeftToRight` becomes `RightToLeft` rather than `LeftToLeft`
+pub struct NameMirror {
+    pattern: Regex,
+    replacements: HashMap<String, String>,
+}
+
+impl NameMirror {
+    pub fn new(swaps: &[NameSwap]) -> Result<Self, String> {
+        let mut replacements = HashMap::new();
+        for swap in swaps {
+            if swap.from.is_empty() || swap.to.is_empty() || swap.from == swap.to {
+                return Err(format!(
+                    "Invalid name swap '{}' <-> '{}'",
+                    swap.from, swap.to
+                ));
+            }
+            replacements.insert(swap.from.clone(), swap.to.clone());
+            replacements.insert(swap.to.clone(), swap.from.clone());
+        }
+        if replacements.is_empty() {
+            return Err("At least one name swap is required".to_string());
+        }
+
+        // Longest first so a fragment never shadows a longer one containing it
+        let mut fragments: Vec<&String> = replacements.keys().collect();
+        fragments.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        let alternation: Vec<String> = fragments.iter().map(|f| regex::escape(f)).collect();
+
+        let pattern =
+            Regex::new(&alternation.join("|")).map_err(|e| format!("Invalid name swaps: {}", e))?;
+
+        Ok(NameMirror {
+            pattern,
+            replacements,
+        })
+    }
+
+    /// The mirrored name, or `None` when it contains none of the fragments
+    pub fn mirror(&self, name: &str) -> Option<String> {
+        if !self.pattern.is_match(name) {
+            return None;
+        }
+        let mirrored = self.pattern.replace_all(name, |caps: &regex::Captures| {
+            self.replacements[&caps[0]].clone()
+        });
+        Some(mirrored.into_owned())
+    }
+}
+
+/// Reflect a zone across the plane. The zone keeps its size; yaw and spawn facing are
+/// reflected so a zone facing the centreline still faces it.
+pub fn mirror_zone(zone: &TeleportZone, plane: &MirrorPlane) -> TeleportZone {
+    let mut mirrored = zone.clone();
+    let reflect_yaw = |yaw: f32| match plane.axis {
+        MirrorAxis::X => normalize_yaw(180.0 - yaw),
+        MirrorAxis::Y => normalize_yaw(-yaw),
+    };
+
+    match plane.axis {
+        MirrorAxis::X => mirrored.position.x = 2.0 * plane.offset - zone.position.x,
+        MirrorAxis::Y => mirrored.position.y = 2.0 * plane.offset - zone.position.y,
+    }
+    mirrored.yaw = reflect_yaw(zone.yaw);
+    mirrored.spawn_yaw = zone.spawn_yaw.map(reflect_yaw);
+
+    mirrored
+}
+
+/// Positions of every copy in the array except the original at row 0, column 0,
+/// labelled with their 1-based row and column
+pub fn array_positions(origin: Vec3, array: &ZoneArray) -> Vec<(u32, u32, Vec3)> {
+    (0..array.rows)
+        .flat_map(|row| (0..array.columns).map(move |column| (row, column)))
+        .filter(|&(row, column)| (row, column) != (0, 0))
+        .map(|(row, column)| {
+            let (r, c) = (row as f32, column as f32);
+            let position = Vec3 {
+                x: origin.x + c * array.column_step.x + r * array.row_step.x,
+                y: origin.y + c * array.column_step.y + r * array.row_step.y,
+                z: origin.z + c * array.column_step.z + r * array.row_step.z,
+            };
+            (row + 1, column + 1, position)
+        })
+        .collect()
+}
+
+/// Keep yaw in (-180, 180]
+fn normalize_yaw(yaw: f32) -> f32 {
+    let wrapped = (yaw + 180.0).rem_euclid(360.0) - 180.0;
+    if wrapped == -180.0 {
+        180.0
+    } else {
+        wrapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(from: &str, to: &str) -> NameSwap {
+        NameSwap {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn swaps_every_fragment_in_one_pass() {
+        let mirror = NameMirror::new(&[swap("Left", "Right")]).unwrap();
+        assert_eq!(mirror.mirror("LeftToRight").as_deref(), Some("RightToLeft"));
+        assert_eq!(mirror.mirror("Center"), None);
+    }
+
+    #[test]
+    fn longer_fragments_win() {
+        let mirror = NameMirror::new(&[swap("L", "R"), swap("Left", "Right")]).unwrap();
+        assert_eq!(mirror.mirror("Left_L").as_deref(), Some("Right_R"));
+    }
+
+    #[test]
+    fn rejects_invalid_swaps() {
+        assert!(NameMirror::new(&[]).is_err());
+        assert!(NameMirror::new(&[swap("Left", "Left")]).is_err());
+        assert!(NameMirror::new(&[swap("", "Right")]).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::models::NodePairing;
+use regex::Regex;
+use std::collections::HashMap;
+
+pub const SWITCH_TAGS: [&str; 3] = ["-Dial", "-Button", "-Lever"];
+pub const COLLIDER_SUFFIX: &str = "-Collider";
+
+/// A node name tag that marks a switch of the given type, e.g. "-Dial" for dials
+#[derive(Debug, Clone)]
+pub struct SwitchTag {
+    pub tag: String,
+    pub switch_type: String,
+}
+
+/// The visual and collider nodes found for a single switch.
+pub struct SwitchNodes {
+    pub pretty_name: String,
+    pub switch_type: String,
+    pub visual: Option<String>,
+    pub collider: Option<String>,
+}
+
+impl SwitchNodes {
+    pub fn pairing(&self) -> NodePairing {
+        match (&self.visual, &self.collider) {
+            (Some(_), None) => NodePairing::MissingCollider,
+            (None, Some(_)) => NodePairing::MissingVisual,
+            _ => NodePairing::Paired,
+        }
+    }
+
+    /// Both halves of the switch that exist in the model
+    pub fn node_names(&self) -> impl Iterator<Item = &String> {
+        self.visual.iter().chain(self.collider.iter())
+    }
+}
+
+/// Everything we learn about a model from walking its node tree
+pub struct ModelScan {
+    pub switches: Vec<SwitchNodes>,
+    pub node_names: Vec<String>,
+    pub parents: HashMap<String, String>, // Named node -> nearest named parent
+}
+
+impl ModelScan {
+    /// Names of a node's ancestors, nearest first
+    pub fn ancestors<'a>(&'a self, node_name: &str) -> Vec<&'a String> {
+        let mut ancestors = Vec::new();
+        let mut current = self.parents.get(node_name);
+        while let Some(parent) = current {
+            // Guard against malformed files that name a node after one of its ancestors
+            if ancestors.contains(&parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = self.parents.get(parent.as_str());
+        }
+        ancestors
+    }
+}
+
+/// Case-insensitive patterns for the switch tags and the collider suffix, compiled once per scan
+struct TagPatterns<'a> {
+    tags: Vec<(Regex, &'a SwitchTag)>,
+    collider: Regex,
+}
+
+impl<'a> TagPatterns<'a> {
+    fn new(tags: &'a [SwitchTag]) -> Self {
+        let pattern = |text: &str| {
+            Regex::new(&format!("(?i){}", regex::escape(text)))
+                .expect("Failed to compile regex pattern")
+        };
+        TagPatterns {
+            tags: tags.iter().map(|tag| (pattern(&tag.tag), tag)).collect(),
+            collider: pattern(COLLIDER_SUFFIX),
+        }
+    }
+}
+
+/// Parse a GLB/glTF file and collect its switches and node names.
+/// A node is a switch when its name contains one of `tags`, the first matching tag wins.
+pub fn scan_model(data: &[u8], tags: &[SwitchTag]) -> Result<ModelScan, String> {
+    let gltf = gltf::Gltf::from_slice(data).map_err(|e| e.to_string())?;
+    let patterns = TagPatterns::new(tags);
+
+    let mut scan = ModelScan {
+        switches: Vec::new(),
+        node_names: Vec::new(),
+        parents: HashMap::new(),
+    };
+
+    for scene in gltf.scenes() {
+        for node in scene.nodes() {
+            traverse_node(node, &patterns, &mut scan, None);
+        }
+    }
+
+    Ok(scan)
+}
+
+/// Read the model referenced by a config file and scan it
+pub fn scan_model_file(model_path: &str, tags: &[SwitchTag]) -> Result<ModelScan, String> {
+    let data = std::fs::read(model_path)
+        .map_err(|e| format!("Failed to read model '{}': {}", model_path, e))?;
+    scan_model(&data, tags)
+}
+
+fn traverse_node(
+    node: gltf::Node,
+    patterns: &TagPatterns,
+    scan: &mut ModelScan,
+    parent: Option<&str>,
+) {
+    if let Some(name) = node.name() {
+        let raw_node_name = name.to_string();
+
+        // Add every node name to the node_names list
+        scan.node_names.push(raw_node_name.clone());
+        if let Some(parent) = parent {
+            scan.parents
+                .insert(raw_node_name.clone(), parent.to_string());
+        }
+
+        for (re, tag) in &patterns.tags {
+            if re.is_match(name) {
+                let is_collider = patterns.collider.is_match(name);
+
+                // Create the pretty name by removing tag and "-Collider"
+                let pretty_name = re.replace_all(name, "");
+                let pretty_name = patterns.collider.replace_all(&pretty_name, "");
+                let pretty_name = pretty_name.trim().to_string();
+
+                // Find the entry for this switch, creating it on first sight of either half
+                let index = match scan
+                    .switches
+                    .iter()
+                    .position(|s| s.pretty_name == pretty_name)
+                {
+                    Some(index) => index,
+                    None => {
+                        scan.switches.push(SwitchNodes {
+                            pretty_name: pretty_name.clone(),
+                            switch_type: tag.switch_type.clone(),
+                            visual: None,
+                            collider: None,
+                        });
+                        scan.switches.len() - 1
+                    }
+                };
+                let entry = &mut scan.switches[index];
+
+                let slot = if is_collider {
+                    &mut entry.collider
+                } else {
+                    &mut entry.visual
+                };
+                match slot {
+                    Some(existing) => println!(
+                        "parse_glb: '{}' duplicates '{}' for switch '{}', ignoring it.",
+                        raw_node_name, existing, pretty_name
+                    ),
+                    None => *slot = Some(raw_node_name.clone()),
+                }
+                break;
+            }
+        }
+    }
+    // Unnamed nodes are transparent, their children hang off the nearest named ancestor
+    let parent = node.name().or(parent);
+    for child in node.children() {
+        traverse_node(child, patterns, scan, parent);
+    }
+}
@@ -1,67 +1,488 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PlaneSwitchData {
-    pub switches: HashMap<String, SwitchData>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SwitchData {
     pub switch_type: SwitchType,
     pub switch_description: String,
     pub movement_axis: MovementAxis,
     pub sound_effect: SoundEffect,
-    pub movement_mode: bool,
+    pub motion: MotionKind,
+    pub units: MotionUnit,
+    /// Moves in steps instead of continuously, the former `movementMode` flag
+    #[serde(default)]
+    pub discrete: bool,
     pub momentary_switch: bool,
     pub bleed_margins: f32,
     pub default_position: f32,
     pub upper_limit: f32,
     pub lower_limit: f32,
-    pub raw_node_name: String, // Added the raw node name field
+    /// Model node the switch was detected from
+    pub raw_node_name: String,
+    /// Mesh the engine animates
+    #[serde(default)]
+    pub visual_node_name: Option<String>,
+    /// Mesh the engine attaches interaction to
+    #[serde(default)]
+    pub collider_node_name: Option<String>,
+    #[serde(default)]
+    pub review_status: ReviewStatus,
+    /// Named detents, empty for continuous controls
+    #[serde(default)]
+    pub positions: Vec<DetentPosition>,
+    /// Extra degrees of freedom, the fields above are axis 0
+    #[serde(default)]
+    pub additional_axes: Vec<AxisConfig>,
+    #[serde(default)]
+    pub axis_couplings: Vec<AxisCoupling>,
+    /// How the primary axis moves on its own once released
+    #[serde(default)]
+    pub behavior: SwitchBehavior,
+    /// Falls back to `soundEffect` when unset
+    #[serde(default)]
+    pub sound_overrides: SwitchSoundOverrides,
+}
+
+/// Sound library assets a switch plays instead of its type-derived `soundEffect`.
+/// The most specific match wins: detent, then direction, then `asset`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchSoundOverrides {
+    #[serde(default)]
+    pub asset: Option<String>,
+    #[serde(default)]
+    pub towards_upper: Option<String>,
+    #[serde(default)]
+    pub towards_lower: Option<String>,
+    /// Detent label -> asset played when it is reached
+    #[serde(default)]
+    pub positions: HashMap<String, String>,
+}
+
+/// A named entry of the config's sound library
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SoundAsset {
+    /// Relative paths are resolved against the model's folder
+    pub file_path: String,
+    #[serde(default = "default_volume")]
+    pub volume: f32,
+    /// Random pitch offset, as a fraction of the original pitch
+    #[serde(default)]
+    pub pitch_variance: f32,
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+/// Physical behavior of a switch. With `momentarySwitch` set and neither direction flagged,
+/// both directions spring back, which is how older files are read.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchBehavior {
+    /// Springs back after being moved towards the upper limit
+    #[serde(default)]
+    pub momentary_upper: bool,
+    /// Springs back after being moved towards the lower limit
+    #[serde(default)]
+    pub momentary_lower: bool,
+    /// Where it springs back to, the default position if unset
+    #[serde(default)]
+    pub return_target: Option<f32>,
+    /// Units per second, the engine picks one if unset
+    #[serde(default)]
+    pub return_speed: Option<f32>,
+    /// 0 (free) to 1 (stiff), a hint for the interaction feel
+    #[serde(default)]
+    pub friction: Option<f32>,
+    /// Force needed to start moving it, a hint as well
+    #[serde(default)]
+    pub resistance: Option<f32>,
+}
+
+impl SwitchBehavior {
+    /// Which directions spring back, as (towards upper, towards lower)
+    pub fn momentary_directions(&self, momentary_switch: bool) -> (bool, bool) {
+        if momentary_switch && !self.momentary_upper && !self.momentary_lower {
+            (true, true)
+        } else {
+            (self.momentary_upper, self.momentary_lower)
+        }
+    }
+}
+
+/// One extra degree of freedom of a multi-axis control such as a trim hat or gated throttle
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AxisConfig {
+    pub movement_axis: MovementAxis,
+    pub motion: MotionKind,
+    pub units: MotionUnit,
+    #[serde(default)]
+    pub discrete: bool,
+    pub momentary_switch: bool,
+    pub bleed_margins: f32,
+    pub default_position: f32,
+    pub upper_limit: f32,
+    pub lower_limit: f32,
+}
+
+/// While axis `whenAxis` is inside `whenRange`, axis `limitAxis` is held inside `limitRange`.
+/// Axes are numbered with the primary axis as 0 followed by the additional axes.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AxisCoupling {
+    pub when_axis: usize,
+    pub when_range: PositionRange,
+    pub limit_axis: usize,
+    pub limit_range: PositionRange,
+}
+
+/// A named detent such as OFF / STBY / ON. `value` lies within the switch limits.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DetentPosition {
+    pub label: String,
+    pub value: f32,
+    #[serde(default)]
+    pub snap_tolerance: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ValidationSeverity {
+    Error,
+    Warning,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationIssue {
+    pub severity: ValidationSeverity,
+    pub section: String,
+    pub key: String,
+    pub message: String,
+}
+
+/// Generated entries start out unreviewed until someone saves them from the editor
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ReviewStatus {
+    #[default]
+    Reviewed,
+    Unreviewed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum SwitchType {
     Lever,
     Button,
     Dial,
     Throttle,
+    #[serde(untagged)]
+    Custom(String), // Defined in the workspace switch type registry
+}
+
+impl SwitchType {
+    pub const ALL: [SwitchType; 4] = [
+        SwitchType::Lever,
+        SwitchType::Button,
+        SwitchType::Dial,
+        SwitchType::Throttle,
+    ];
+
+    /// The id the type is stored under
+    pub fn id(&self) -> &str {
+        match self {
+            SwitchType::Lever => "lever",
+            SwitchType::Button => "button",
+            SwitchType::Dial => "dial",
+            SwitchType::Throttle => "throttle",
+            SwitchType::Custom(id) => id,
+        }
+    }
+}
+
+/// Any id is accepted since custom types come from the workspace registry, so the schema only
+/// lists the built-in ones as examples
+impl JsonSchema for SwitchType {
+    fn schema_name() -> String {
+        "SwitchType".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A built-in switch type or the id of one from the workspace switch type registry"
+                        .to_string(),
+                ),
+                examples: SwitchType::ALL
+                    .iter()
+                    .map(|switch_type| switch_type.id().into())
+                    .collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
 }
 
+/// One entry of the workspace switch type registry
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchTypeDefinition {
+    pub id: SwitchType,
+    pub display_name: String,
+    #[serde(default)]
+    pub detection_patterns: Vec<String>, // Node name tags such as "-Dial", matched case-insensitively
+    pub defaults: SwitchTypeDefaults, // Starting motion, sound and limits for new switches
+    #[serde(default)]
+    pub allowed_fields: Option<Vec<String>>, // Optional switch fields this type may use, all if unset
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 pub enum MovementAxis {
     X,
     Y,
     Z,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// How a control moves along its axis
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MotionKind {
+    Rotate,
+    Translate,
+}
+
+/// Units an axis' limits, detents and margins are written in
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+pub enum MotionUnit {
+    #[serde(rename = "degrees")]
+    Degrees,
+    #[serde(rename = "radians")]
+    Radians,
+    #[serde(rename = "cm")]
+    Centimeters,
+    #[serde(rename = "m")]
+    Meters,
+}
+
+impl MotionUnit {
+    /// The kind of motion these units measure
+    pub fn motion(self) -> MotionKind {
+        match self {
+            MotionUnit::Degrees | MotionUnit::Radians => MotionKind::Rotate,
+            MotionUnit::Centimeters | MotionUnit::Meters => MotionKind::Translate,
+        }
+    }
+}
+
+/// A built-in engine sound, or the name of an entry in the config's sound library
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum SoundEffect {
     LeverSound,
     ButtonSound,
     DialSound,
     ThrottleSound,
+    #[serde(untagged)]
+    Library(String), // Lets registry types default to a library sound
+}
+
+impl SoundEffect {
+    /// The sound library entry this sound refers to, if it isn't a built-in one
+    pub fn library_asset(&self) -> Option<&String> {
+        match self {
+            SoundEffect::Library(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Built-in sounds are listed as examples, since library names are accepted too
+impl JsonSchema for SoundEffect {
+    fn schema_name() -> String {
+        "SoundEffect".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A built-in engine sound or the name of an entry in the sound library"
+                        .to_string(),
+                ),
+                examples: ["leverSound", "buttonSound", "dialSound", "throttleSound"]
+                    .iter()
+                    .map(|id| (*id).into())
+                    .collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
 }
 
+/// Values a freshly generated switch of a given type starts with
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct NewSwitchSubmission {
-    pub switch_type: SwitchType,
-    pub switch_name: String,
-    pub switch_description: String,
+pub struct SwitchTypeDefaults {
     pub movement_axis: MovementAxis,
-    pub movement_mode: bool,
+    pub sound_effect: SoundEffect,
+    pub motion: MotionKind,
+    pub units: MotionUnit,
+    #[serde(default)]
+    pub discrete: bool,
     pub momentary_switch: bool,
     pub bleed_margins: f32,
     pub default_position: f32,
     pub upper_limit: f32,
     pub lower_limit: f32,
+}
+
+pub type SwitchTypeDefaultsProfile = HashMap<SwitchType, SwitchTypeDefaults>;
+
+/// A partial switch update. Only the fields that are set get touched.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchPatch {
+    #[serde(default)]
+    pub switch_type: Option<SwitchType>,
+    #[serde(default)]
+    pub switch_description: Option<String>,
+    #[serde(default)]
+    pub description_prefix: Option<String>,
+    #[serde(default)]
+    pub movement_axis: Option<MovementAxis>,
+    #[serde(default)]
+    pub sound_effect: Option<SoundEffect>, // Derived from the new type when only the type changes
+    #[serde(default)]
+    pub motion: Option<MotionKind>,
+    #[serde(default)]
+    pub units: Option<MotionUnit>,
+    #[serde(default)]
+    pub discrete: Option<bool>,
+    #[serde(default)]
+    pub momentary_switch: Option<bool>,
+    #[serde(default)]
+    pub bleed_margins: Option<f32>,
+    #[serde(default)]
+    pub default_position: Option<f32>,
+    #[serde(default)]
+    pub upper_limit: Option<f32>,
+    #[serde(default)]
+    pub lower_limit: Option<f32>,
+    #[serde(default)]
+    pub positions: Option<Vec<DetentPosition>>,
+    #[serde(default)]
+    pub additional_axes: Option<Vec<AxisConfig>>,
+    #[serde(default)]
+    pub axis_couplings: Option<Vec<AxisCoupling>>,
+    #[serde(default)]
+    pub behavior: Option<SwitchBehavior>,
+    #[serde(default)]
+    pub sound_overrides: Option<SwitchSoundOverrides>,
+    #[serde(default)]
+    pub review_status: Option<ReviewStatus>,
+}
+
+/// Batch configuration rule. A switch matches when every pattern that is set matches
+/// (`*` and `?` wildcards, case-insensitive).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchRule {
+    pub name: String,
+    #[serde(default)]
+    pub node_pattern: Option<String>, // Matched against the switch's raw node name
+    #[serde(default)]
+    pub parent_pattern: Option<String>, // Matched against any ancestor node in the model
+    pub set: SwitchPatch,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchChange {
+    pub switch_key: String,
+    pub rules: Vec<String>,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SwitchUpdateOutcome {
+    Updated,
+    Unchanged,
+    NotFound,
+    Rejected,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchUpdateResult {
+    pub switch_key: String,
+    pub outcome: SwitchUpdateOutcome,
+    pub changes: Vec<FieldChange>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSwitchSubmission {
+    pub switch_type: SwitchType,
+    pub switch_name: String,
+    pub switch_description: String,
+    // Omitted fields fall back to the workspace defaults for the switch type
+    #[serde(default)]
+    pub movement_axis: Option<MovementAxis>,
+    #[serde(default)]
+    pub motion: Option<MotionKind>,
+    #[serde(default)]
+    pub units: Option<MotionUnit>,
+    #[serde(default)]
+    pub discrete: Option<bool>,
+    #[serde(default)]
+    pub momentary_switch: Option<bool>,
+    #[serde(default)]
+    pub bleed_margins: Option<f32>,
+    #[serde(default)]
+    pub default_position: Option<f32>,
+    #[serde(default)]
+    pub upper_limit: Option<f32>,
+    #[serde(default)]
+    pub lower_limit: Option<f32>,
     pub raw_node_name: String, // Added the raw node name field
+    #[serde(default)]
+    pub visual_node_name: Option<String>,
+    #[serde(default)]
+    pub collider_node_name: Option<String>,
+    #[serde(default)]
+    pub positions: Vec<DetentPosition>,
+    #[serde(default)]
+    pub additional_axes: Vec<AxisConfig>,
+    #[serde(default)]
+    pub axis_couplings: Vec<AxisCoupling>,
+    #[serde(default)]
+    pub behavior: SwitchBehavior,
+    #[serde(default)]
+    pub sound_overrides: SwitchSoundOverrides,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -71,17 +492,265 @@ pub struct NewTeleportZoneSubmission {
     pub x: f32,
     pub y: f32,
     pub z: f32,
+    #[serde(flatten)]
+    pub properties: TeleportZoneProperties,
+    #[serde(default)]
+    pub snap_to_floor: bool, // Drop the zone onto the model's floor below the given point
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl NewTeleportZoneSubmission {
+    /// The submitted zone, with anything left out at its default
+    pub fn to_zone(&self) -> TeleportZone {
+        let mut zone = TeleportZone::at(Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        });
+        self.properties.apply_to(&mut zone);
+        zone
+    }
+}
+
+/// Teleport zone fields the add/update/rename commands accept next to the position.
+/// Unset fields keep the zone's current value, or the default for a new zone.
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TeleportZoneProperties {
+    #[serde(default)]
+    pub yaw: Option<f32>,
+    #[serde(default)]
+    pub shape: Option<TeleportZoneShape>,
+    #[serde(default)]
+    pub dimensions: Option<Vec3>,
+    #[serde(default)]
+    pub spawn_yaw: Option<f32>,
+}
+
+impl TeleportZoneProperties {
+    pub fn apply_to(&self, zone: &mut TeleportZone) {
+        if let Some(yaw) = self.yaw {
+            zone.yaw = yaw;
+        }
+        if let Some(shape) = self.shape {
+            zone.shape = shape;
+        }
+        if let Some(dimensions) = self.dimensions {
+            zone.dimensions = dimensions;
+        }
+        if let Some(spawn_yaw) = self.spawn_yaw {
+            zone.spawn_yaw = Some(spawn_yaw);
+        }
+    }
+}
+
+/// A plane config file as stored in the `plane_configs` folder
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FullConfigFile {
+    /// Format version of the file, files without one are read as version 1
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u64,
     pub plane_name: String,
+    /// Path of the aircraft model the switches and zones refer to
     pub model_path: String,
     pub teleport_zones: HashMap<String, TeleportZone>,
     pub switches: HashMap<String, SwitchData>,
     #[serde(default)]
-    pub non_switch_raw_node_names: Vec<String>,
+    pub node_classification: NodeClassification,
+    #[serde(default)]
+    pub panels: HashMap<String, Panel>,
+    #[serde(default)]
+    pub interlocks: HashMap<String, Interlock>,
+    #[serde(default)]
+    pub composite_controls: HashMap<String, CompositeControl>,
+    #[serde(default)]
+    pub sound_library: HashMap<String, SoundAsset>,
+    #[serde(default)]
+    pub teleport_links: HashMap<String, TeleportLink>,
+    #[serde(default)]
+    pub default_spawn_zone: Option<String>,
+    #[serde(default)]
+    pub crew_stations: HashMap<String, CrewStation>,
+}
+
+/// One logical control made of several switch entries, e.g. a guard cover over a toggle.
+/// Cover positions are on the cover switch's own axis.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositeControl {
+    pub cover_switch: String,
+    pub guarded_switch: String,
+    pub cover_closed_position: f32,
+    pub cover_open_position: f32,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Gates a switch, or one range of it, until every condition holds
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Interlock {
+    pub switch: String,
+    #[serde(default)]
+    pub description: String,
+    /// None gates all movement
+    #[serde(default)]
+    pub gated_range: Option<PositionRange>,
+    pub conditions: Vec<InterlockCondition>,
+}
+
+/// Another switch must sit at a named detent or within a value range
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InterlockCondition {
+    pub switch: String,
+    #[serde(default)]
+    pub position: Option<String>,
+    #[serde(default)]
+    pub range: Option<PositionRange>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+fn legacy_schema_version() -> u64 {
+    1
+}
+
+/// A cockpit panel such as the overhead or pedestal. Switch lists are kept in display order.
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Panel {
+    pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub order: u32,
+    /// Model node the panel was derived from
+    #[serde(default)]
+    pub source_node: Option<String>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    #[serde(default)]
+    pub switches: Vec<String>,
+    #[serde(default)]
+    pub groups: Vec<PanelGroup>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PanelGroup {
+    pub name: String,
+    #[serde(default)]
+    pub source_node: Option<String>,
+    #[serde(default)]
+    pub switches: Vec<String>,
+    #[serde(default)]
+    pub groups: Vec<PanelGroup>,
+}
+
+impl Panel {
+    /// Every switch on the panel, including those in nested groups
+    pub fn all_switches(&self) -> Vec<&String> {
+        let mut switches: Vec<&String> = self.switches.iter().collect();
+        for group in &self.groups {
+            group.collect_switches(&mut switches);
+        }
+        switches
+    }
+
+    /// Take the given switches off the panel and out of its groups
+    pub fn remove_switches(&mut self, keys: &[&String]) {
+        self.switches.retain(|key| !keys.contains(&key));
+        for group in &mut self.groups {
+            group.remove_switches(keys);
+        }
+    }
+
+    /// Add the switches and groups of `other`, merging groups that share a name
+    pub fn merge(&mut self, other: Panel) {
+        self.switches.extend(other.switches);
+        merge_groups(&mut self.groups, other.groups);
+    }
+}
+
+fn merge_groups(groups: &mut Vec<PanelGroup>, others: Vec<PanelGroup>) {
+    for other in others {
+        match groups.iter_mut().find(|group| group.name == other.name) {
+            Some(group) => {
+                group.switches.extend(other.switches);
+                merge_groups(&mut group.groups, other.groups);
+            }
+            None => groups.push(other),
+        }
+    }
+}
+
+impl PanelGroup {
+    fn collect_switches<'a>(&'a self, switches: &mut Vec<&'a String>) {
+        switches.extend(&self.switches);
+        for group in &self.groups {
+            group.collect_switches(switches);
+        }
+    }
+
+    fn remove_switches(&mut self, keys: &[&String]) {
+        self.switches.retain(|key| !keys.contains(&key));
+        for group in &mut self.groups {
+            group.remove_switches(keys);
+        }
+    }
+}
+
+/// What a model node represents in the cockpit
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeCategory {
+    Switch,
+    Static,
+    Gauge,
+    Display,
+    Door,
+    Ignored,
+}
+
+/// Assigns a category to every node whose name matches `pattern` (`*` and `?` wildcards)
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeClassificationRule {
+    pub pattern: String,
+    pub category: NodeCategory,
+}
+
+/// The `nodeClassification` config section. Manual assignments win over rules.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeClassification {
+    #[serde(default)]
+    pub rules: Vec<NodeClassificationRule>,
+    #[serde(default)]
+    pub manual: HashMap<String, NodeCategory>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ClassificationSource {
+    Manual,
+    Rule,
+    Detected,
+    Default,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassifiedNode {
+    pub raw_node_name: String,
+    pub category: NodeCategory,
+    pub source: ClassificationSource,
 }
 
 #[derive(Serialize)]
@@ -92,6 +761,18 @@ pub struct SwitchItem {
     pub is_configured: bool,
     pub switch_type: String,
     pub raw_node_name: String, // Added to ensure we track this in the UI
+    pub visual_node_name: Option<String>,
+    pub collider_node_name: Option<String>,
+    pub pairing: NodePairing,
+}
+
+/// Whether a detected switch has both its visual mesh and its `-Collider` node.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum NodePairing {
+    Paired,
+    MissingCollider,
+    MissingVisual,
 }
 
 #[derive(Serialize)]
@@ -107,10 +788,208 @@ pub struct PlaneConfigFile {
     pub model_path: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TeleportZone {
+/// A point or size in config space, where z is up as in the engine
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, JsonSchema)]
+pub struct Vec3 {
     pub x: f32,
     pub y: f32,
     pub z: f32,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TeleportZoneShape {
+    #[default]
+    Box,
+    Cylinder,
+    Point,
+}
+
+/// Size given to zones that don't state one: a 1 m square standing area, 2 m tall
+pub const DEFAULT_ZONE_DIMENSIONS: Vec3 = Vec3 {
+    x: 1.0,
+    y: 1.0,
+    z: 2.0,
+};
+
+fn default_zone_dimensions() -> Vec3 {
+    DEFAULT_ZONE_DIMENSIONS
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TeleportZone {
+    /// Centre of the zone's floor
+    pub position: Vec3,
+    /// Degrees around the up axis
+    #[serde(default)]
+    pub yaw: f32,
+    #[serde(default)]
+    pub shape: TeleportZoneShape,
+    /// Box: full extents. Cylinder: x is the diameter and z the height. Points ignore it.
+    #[serde(default = "default_zone_dimensions")]
+    pub dimensions: Vec3,
+    /// Facing on arrival, the zone's yaw when unset
+    #[serde(default)]
+    pub spawn_yaw: Option<f32>,
+}
+
+impl TeleportZone {
+    /// A zone at `position` with every other field at its default
+    pub fn at(position: Vec3) -> Self {
+        TeleportZone {
+            position,
+            yaw: 0.0,
+            shape: TeleportZoneShape::default(),
+            dimensions: DEFAULT_ZONE_DIMENSIONS,
+            spawn_yaw: None,
+        }
+    }
+}
+
+/// An edge of the teleport graph: trainees standing in `from` may teleport to `to`
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TeleportLink {
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub bidirectional: bool,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl TeleportLink {
+    pub fn references(&self, zone_name: &str) -> bool {
+        self.from == zone_name || self.to == zone_name
+    }
+}
+
+/// A crew position such as pilot or navigator, with the zones and controls that belong to it
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CrewStation {
+    pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub zones: Vec<String>,
+    /// Where the role starts, the first zone when unset
+    #[serde(default)]
+    pub spawn_zone: Option<String>,
+    #[serde(default)]
+    pub switches: Vec<String>,
+    /// Every switch on these panels belongs to the station too
+    #[serde(default)]
+    pub panels: Vec<String>,
+}
+
+impl CrewStation {
+    /// Switches the station can operate: its own plus those on its panels, sorted
+    pub fn reachable_switches(&self, panels: &HashMap<String, Panel>) -> Vec<String> {
+        let mut switches: Vec<String> = self
+            .switches
+            .iter()
+            .chain(
+                self.panels
+                    .iter()
+                    .filter_map(|key| panels.get(key))
+                    .flat_map(|panel| panel.all_switches()),
+            )
+            .cloned()
+            .collect();
+        switches.sort();
+        switches.dedup();
+        switches
+    }
+}
+
+/// The axis a mirror plane is perpendicular to. Mirroring is only offered across
+/// vertical planes, so zones stay on their floors.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MirrorAxis {
+    X,
+    Y,
+}
+
+/// A vertical plane in config space, e.g. the aircraft centreline with `offset` 0
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct MirrorPlane {
+    pub axis: MirrorAxis,
+    #[serde(default)]
+    pub offset: f32,
+}
+
+/// A pair of name fragments swapped in both directions when mirroring, e.g. `Left` and `Right`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NameSwap {
+    pub from: String,
+    pub to: String,
+}
+
+pub fn default_name_swaps() -> Vec<NameSwap> {
+    [("Left", "Right"), ("left", "right"), ("LEFT", "RIGHT")]
+        .into_iter()
+        .map(|(from, to)| NameSwap {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+        .collect()
+}
+
+/// Copies of a zone laid out on a grid; a single row gives a line
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneArray {
+    pub columns: u32,
+    #[serde(default = "default_array_rows")]
+    pub rows: u32,
+    pub column_step: Vec3, // Offset between neighbouring columns
+    #[serde(default)]
+    pub row_step: Vec3,
+}
+
+fn default_array_rows() -> u32 {
+    1
+}
+
+/// What an import does with an entry whose name is already taken
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    #[default]
+    Skip,
+    Overwrite,
+    Rename, // Append the first free `_2`, `_3`, ... suffix
+}
+
+/// Outcome of a teleport zone import, or what it would do on a dry run
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneImportReport {
+    pub dry_run: bool,
+    pub added: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub renamed: Vec<RenamedEntry>,
+    pub skipped: Vec<String>,
+    pub errors: Vec<String>, // Only returned on a dry run, a real import fails on any error
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedEntry {
+    pub from: String,
+    pub to: String,
+}
+
+/// Outcome of a switch spreadsheet import, or what it would do on a dry run
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchSheetImport {
+    pub dry_run: bool,
+    pub results: Vec<SwitchUpdateResult>, // Unknown keys come back as `notFound`
+    pub errors: Vec<String>,              // Rows without a key
+}
@@ -0,0 +1,153 @@
+use crate::model_scan::SwitchNodes;
+use crate::models::{
+    MotionKind, MotionUnit, MovementAxis, ReviewStatus, SoundEffect, SwitchBehavior, SwitchData,
+    SwitchSoundOverrides, SwitchType, SwitchTypeDefaults, SwitchTypeDefaultsProfile,
+};
+use crate::utils;
+use tauri::AppHandle;
+
+/// Built-in starting points for the built-in switch types
+pub fn builtin_defaults(switch_type: &SwitchType) -> SwitchTypeDefaults {
+    match switch_type {
+        SwitchType::Lever => SwitchTypeDefaults {
+            movement_axis: MovementAxis::X,
+            sound_effect: SoundEffect::LeverSound,
+            motion: MotionKind::Rotate,
+            units: MotionUnit::Degrees,
+            discrete: false,
+            momentary_switch: false,
+            bleed_margins: 0.0,
+            default_position: 0.0,
+            upper_limit: 30.0,
+            lower_limit: -30.0,
+        },
+        SwitchType::Button => SwitchTypeDefaults {
+            movement_axis: MovementAxis::Z,
+            sound_effect: SoundEffect::ButtonSound,
+            motion: MotionKind::Translate,
+            units: MotionUnit::Meters,
+            discrete: false,
+            momentary_switch: true,
+            bleed_margins: 0.0,
+            default_position: 0.0,
+            upper_limit: 0.005,
+            lower_limit: 0.0,
+        },
+        SwitchType::Dial => SwitchTypeDefaults {
+            movement_axis: MovementAxis::Y,
+            sound_effect: SoundEffect::DialSound,
+            motion: MotionKind::Rotate,
+            units: MotionUnit::Degrees,
+            discrete: false,
+            momentary_switch: false,
+            bleed_margins: 0.0,
+            default_position: 0.0,
+            upper_limit: 360.0,
+            lower_limit: 0.0,
+        },
+        SwitchType::Throttle => SwitchTypeDefaults {
+            movement_axis: MovementAxis::X,
+            sound_effect: SoundEffect::ThrottleSound,
+            motion: MotionKind::Rotate,
+            units: MotionUnit::Degrees,
+            discrete: false,
+            momentary_switch: false,
+            bleed_margins: 0.0,
+            default_position: 0.0,
+            upper_limit: 45.0,
+            lower_limit: 0.0,
+        },
+        // Only reached for ids missing from the registry, treat them like the plainest control
+        SwitchType::Custom(_) => builtin_defaults(&SwitchType::Lever),
+    }
+}
+
+/// Load the workspace defaults profile, filling any missing type with the defaults from its
+/// registry entry
+pub fn load_profile(app_handle: &AppHandle) -> Result<SwitchTypeDefaultsProfile, String> {
+    let file_path = utils::build_workspace_file_path(app_handle, crate::SWITCH_TYPE_DEFAULTS_FILE)?;
+    let mut json_data = utils::load_json_file(&file_path)?;
+    if let Some(entries) = json_data.as_object_mut() {
+        for (switch_type, entry) in entries.iter_mut() {
+            if let Some(entry) = entry.as_object_mut() {
+                crate::migration::replace_movement_mode(entry, Some(switch_type));
+            }
+        }
+    }
+    let mut profile: SwitchTypeDefaultsProfile = utils::deserialize_json(json_data)?;
+
+    for definition in crate::switch_types::load_registry(app_handle)? {
+        profile.entry(definition.id).or_insert(definition.defaults);
+    }
+
+    Ok(profile)
+}
+
+/// Save the workspace defaults profile
+pub fn save_profile(
+    app_handle: &AppHandle,
+    profile: &SwitchTypeDefaultsProfile,
+) -> Result<(), String> {
+    let file_path = utils::build_workspace_file_path(app_handle, crate::SWITCH_TYPE_DEFAULTS_FILE)?;
+    utils::save_json_file(&file_path, &utils::serialize_to_json(profile)?)
+}
+
+/// The profile entry for a switch type, or its built-in defaults if the profile lacks one
+pub fn defaults_for(
+    profile: &SwitchTypeDefaultsProfile,
+    switch_type: &SwitchType,
+) -> SwitchTypeDefaults {
+    profile
+        .get(switch_type)
+        .cloned()
+        .unwrap_or_else(|| builtin_defaults(switch_type))
+}
+
+/// Overwrite every type-driven field of a switch, keeping its description and node names
+pub fn apply_defaults(switch_data: &mut SwitchData, defaults: &SwitchTypeDefaults) {
+    switch_data.movement_axis = defaults.movement_axis.clone();
+    switch_data.sound_effect = defaults.sound_effect.clone();
+    switch_data.motion = defaults.motion;
+    switch_data.units = defaults.units;
+    switch_data.discrete = defaults.discrete;
+    switch_data.momentary_switch = defaults.momentary_switch;
+    switch_data.bleed_margins = defaults.bleed_margins;
+    switch_data.default_position = defaults.default_position;
+    switch_data.upper_limit = defaults.upper_limit;
+    switch_data.lower_limit = defaults.lower_limit;
+}
+
+/// Build an unreviewed config entry for a switch detected in the model
+pub fn seeded_switch(
+    switch_type: SwitchType,
+    nodes: &SwitchNodes,
+    defaults: &SwitchTypeDefaults,
+) -> SwitchData {
+    SwitchData {
+        switch_type,
+        switch_description: String::new(),
+        movement_axis: defaults.movement_axis.clone(),
+        sound_effect: defaults.sound_effect.clone(),
+        motion: defaults.motion,
+        units: defaults.units,
+        discrete: defaults.discrete,
+        momentary_switch: defaults.momentary_switch,
+        bleed_margins: defaults.bleed_margins,
+        default_position: defaults.default_position,
+        upper_limit: defaults.upper_limit,
+        lower_limit: defaults.lower_limit,
+        raw_node_name: nodes
+            .visual
+            .clone()
+            .or_else(|| nodes.collider.clone())
+            .unwrap_or_default(),
+        visual_node_name: nodes.visual.clone(),
+        collider_node_name: nodes.collider.clone(),
+        review_status: ReviewStatus::Unreviewed,
+        positions: vec![],
+        additional_axes: vec![],
+        axis_couplings: vec![],
+        behavior: SwitchBehavior::default(),
+        sound_overrides: SwitchSoundOverrides::default(),
+    }
+}
@@ -0,0 +1,100 @@
+use crate::models::{FieldChange, SwitchData, SwitchPatch, SwitchTypeDefaultsProfile};
+use crate::switch_defaults;
+use crate::utils;
+use std::collections::BTreeSet;
+
+/// Apply a partial update to a switch, leaving every unset field alone. A new switch type
+/// brings that type's sound from `profile` unless the patch names a sound as well.
+pub fn apply_patch(
+    switch_data: &mut SwitchData,
+    patch: &SwitchPatch,
+    profile: &SwitchTypeDefaultsProfile,
+) {
+    if let Some(switch_type) = &patch.switch_type {
+        if *switch_type != switch_data.switch_type {
+            switch_data.sound_effect =
+                switch_defaults::defaults_for(profile, switch_type).sound_effect;
+        }
+        switch_data.switch_type = switch_type.clone();
+    }
+    if let Some(sound_effect) = &patch.sound_effect {
+        switch_data.sound_effect = sound_effect.clone();
+    }
+    if let Some(description) = &patch.switch_description {
+        switch_data.switch_description = description.clone();
+    }
+    // Only prefix once so re-applying the same patch is harmless
+    if let Some(prefix) = &patch.description_prefix {
+        if !switch_data.switch_description.starts_with(prefix.as_str()) {
+            switch_data.switch_description =
+                format!("{}{}", prefix, switch_data.switch_description);
+        }
+    }
+    if let Some(movement_axis) = &patch.movement_axis {
+        switch_data.movement_axis = movement_axis.clone();
+    }
+    if let Some(motion) = patch.motion {
+        switch_data.motion = motion;
+    }
+    if let Some(units) = patch.units {
+        switch_data.units = units;
+    }
+    if let Some(discrete) = patch.discrete {
+        switch_data.discrete = discrete;
+    }
+    if let Some(momentary_switch) = patch.momentary_switch {
+        switch_data.momentary_switch = momentary_switch;
+    }
+    if let Some(bleed_margins) = patch.bleed_margins {
+        switch_data.bleed_margins = bleed_margins;
+    }
+    if let Some(default_position) = patch.default_position {
+        switch_data.default_position = default_position;
+    }
+    if let Some(upper_limit) = patch.upper_limit {
+        switch_data.upper_limit = upper_limit;
+    }
+    if let Some(lower_limit) = patch.lower_limit {
+        switch_data.lower_limit = lower_limit;
+    }
+    if let Some(positions) = &patch.positions {
+        switch_data.positions = positions.clone();
+    }
+    if let Some(additional_axes) = &patch.additional_axes {
+        switch_data.additional_axes = additional_axes.clone();
+    }
+    if let Some(axis_couplings) = &patch.axis_couplings {
+        switch_data.axis_couplings = axis_couplings.clone();
+    }
+    if let Some(behavior) = &patch.behavior {
+        switch_data.behavior = behavior.clone();
+    }
+    if let Some(sound_overrides) = &patch.sound_overrides {
+        switch_data.sound_overrides = sound_overrides.clone();
+    }
+    if let Some(review_status) = patch.review_status {
+        switch_data.review_status = review_status;
+    }
+}
+
+/// List the serialized fields that differ between two versions of a switch
+pub fn diff_switch(before: &SwitchData, after: &SwitchData) -> Result<Vec<FieldChange>, String> {
+    let before = utils::serialize_to_json(before)?;
+    let after = utils::serialize_to_json(after)?;
+
+    let (Some(before), Some(after)) = (before.as_object(), after.as_object()) else {
+        return Err("Expected switches to serialize to objects".to_string());
+    };
+
+    let fields: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+
+    Ok(fields
+        .into_iter()
+        .filter(|field| before.get(*field) != after.get(*field))
+        .map(|field| FieldChange {
+            field: field.clone(),
+            before: before.get(field).cloned().unwrap_or_default(),
+            after: after.get(field).cloned().unwrap_or_default(),
+        })
+        .collect())
+}
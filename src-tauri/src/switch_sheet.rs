@@ -0,0 +1,311 @@
+use crate::models::{SwitchData, SwitchPatch};
+use calamine::Reader;
+use rust_xlsxwriter::{Format, Workbook};
+use serde::de::DeserializeOwned;
+use serde_json::Value as Json;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Spreadsheet columns in order. `key` identifies the switch; `rawNodeName` and `units` are
+/// there for reference and must not be edited.
+pub const COLUMNS: [&str; 10] = [
+    "key",
+    "rawNodeName",
+    "switchType",
+    "movementAxis",
+    "units",
+    "lowerLimit",
+    "upperLimit",
+    "defaultPosition",
+    "switchDescription",
+    "reviewStatus",
+];
+
+/// Columns written as numbers in XLSX so they can be edited as numbers
+const NUMBER_COLUMNS: [&str; 3] = ["lowerLimit", "upperLimit", "defaultPosition"];
+
+/// A row read back from a spreadsheet, as cell text keyed by column name
+pub struct SheetRow {
+    pub line: usize,
+    pub cells: HashMap<String, String>,
+}
+
+impl SheetRow {
+    pub fn key(&self) -> Option<&str> {
+        self.cell("key")
+    }
+
+    fn cell(&self, column: &str) -> Option<&str> {
+        self.cells
+            .get(column)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// The edits this row makes to `switch_data`. Empty cells leave a field alone; invalid
+    /// values and edits to the reference columns are reported together.
+    pub fn to_patch(&self, switch_data: &SwitchData) -> Result<SwitchPatch, String> {
+        let mut errors = Vec::new();
+
+        if let Some(raw_node_name) = self.cell("rawNodeName") {
+            if raw_node_name != switch_data.raw_node_name {
+                errors.push("rawNodeName can't be changed from a spreadsheet".to_string());
+            }
+        }
+        if let Some(units) = self.cell("units") {
+            if Some(units) != json_text(&switch_data.units).as_deref() {
+                errors.push("units can't be changed from a spreadsheet".to_string());
+            }
+        }
+
+        let patch = SwitchPatch {
+            switch_type: self.parse_enum("switchType", &mut errors),
+            movement_axis: self.parse_enum("movementAxis", &mut errors),
+            lower_limit: self.parse_number("lowerLimit", &mut errors),
+            upper_limit: self.parse_number("upperLimit", &mut errors),
+            default_position: self.parse_number("defaultPosition", &mut errors),
+            switch_description: self.cell("switchDescription").map(str::to_string),
+            review_status: self.parse_enum("reviewStatus", &mut errors),
+            ..Default::default()
+        };
+
+        if errors.is_empty() {
+            Ok(patch)
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    fn parse_number(&self, column: &str, errors: &mut Vec<String>) -> Option<f32> {
+        let value = self.cell(column)?;
+        value
+            .parse()
+            .map_err(|_| errors.push(format!("{} '{}' is not a number", column, value)))
+            .ok()
+    }
+
+    fn parse_enum<T: DeserializeOwned>(&self, column: &str, errors: &mut Vec<String>) -> Option<T> {
+        let value = self.cell(column)?;
+        serde_json::from_value(Json::String(value.to_string()))
+            .map_err(|_| errors.push(format!("{} '{}' is not a valid value", column, value)))
+            .ok()
+    }
+}
+
+/// The cells of one switch, in `COLUMNS` order
+fn switch_cells(key: &str, switch_data: &SwitchData) -> Vec<String> {
+    vec![
+        key.to_string(),
+        switch_data.raw_node_name.clone(),
+        switch_data.switch_type.id().to_string(),
+        json_text(&switch_data.movement_axis).unwrap_or_default(),
+        json_text(&switch_data.units).unwrap_or_default(),
+        switch_data.lower_limit.to_string(),
+        switch_data.upper_limit.to_string(),
+        switch_data.default_position.to_string(),
+        switch_data.switch_description.clone(),
+        json_text(&switch_data.review_status).unwrap_or_default(),
+    ]
+}
+
+/// How a unit-only enum is spelled in the config
+fn json_text<T: serde::Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value) {
+        Ok(Json::String(text)) => Some(text),
+        _ => None,
+    }
+}
+
+fn is_xlsx(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("xlsx"))
+}
+
+/// Write every switch sorted by key, as XLSX when the path ends in `.xlsx` and CSV otherwise
+pub fn write_switches(path: &Path, switches: &HashMap<String, SwitchData>) -> Result<(), String> {
+    let mut keys: Vec<&String> = switches.keys().collect();
+    keys.sort();
+    let rows: Vec<Vec<String>> = keys
+        .into_iter()
+        .map(|key| switch_cells(key, &switches[key]))
+        .collect();
+
+    if is_xlsx(path) {
+        write_xlsx(path, &rows)
+    } else {
+        write_csv(path, &rows)
+    }
+}
+
+fn write_csv(path: &Path, rows: &[Vec<String>]) -> Result<(), String> {
+    let mut writer =
+        csv::Writer::from_path(path).map_err(|e| format!("Failed to create {:?}: {}", path, e))?;
+
+    for record in std::iter::once(COLUMNS.map(str::to_string).to_vec()).chain(rows.to_vec()) {
+        writer
+            .write_record(&record)
+            .map_err(|e| format!("Failed to write {:?}: {}", path, e))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| format!("Failed to write {:?}: {}", path, e))
+}
+
+fn write_xlsx(path: &Path, rows: &[Vec<String>]) -> Result<(), String> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let header_format = Format::new().set_bold();
+    let write_error = |e: rust_xlsxwriter::XlsxError| format!("Failed to write {:?}: {}", path, e);
+
+    worksheet.set_name("Switches").map_err(write_error)?;
+    for (column, name) in COLUMNS.iter().enumerate() {
+        worksheet
+            .write_string_with_format(0, column as u16, *name, &header_format)
+            .map_err(write_error)?;
+    }
+
+    for (row, cells) in rows.iter().enumerate() {
+        let row = row as u32 + 1;
+        for (column, value) in cells.iter().enumerate() {
+            let number = NUMBER_COLUMNS
+                .contains(&COLUMNS[column])
+                .then(|| value.parse::<f64>().ok())
+                .flatten();
+            match number {
+                Some(number) => worksheet.write_number(row, column as u16, number),
+                None => worksheet.write_string(row, column as u16, value),
+            }
+            .map_err(write_error)?;
+        }
+    }
+
+    worksheet.set_freeze_panes(1, 0).map_err(write_error)?;
+    worksheet.autofit();
+
+    workbook.save(path).map_err(write_error)
+}
+
+/// Read the rows of a switch spreadsheet written by `write_switches`, from the first sheet
+/// of an XLSX file or from a CSV file. Columns are matched by their header.
+pub fn read_switches(path: &Path) -> Result<Vec<SheetRow>, String> {
+    let table = if is_xlsx(path) {
+        read_xlsx(path)?
+    } else {
+        read_csv(path)?
+    };
+
+    let mut table = table.into_iter();
+    let headers: Vec<String> = table
+        .next()
+        .ok_or_else(|| format!("{:?} is empty", path))?
+        .into_iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+    if !headers.iter().any(|header| header == "key") {
+        return Err(format!("{:?} has no 'key' column", path));
+    }
+
+    Ok(table
+        .enumerate()
+        .filter(|(_, cells)| cells.iter().any(|cell| !cell.trim().is_empty()))
+        .map(|(index, cells)| SheetRow {
+            line: index + 2, // 1-based, after the header
+            cells: headers.iter().cloned().zip(cells).collect(),
+        })
+        .collect())
+}
+
+fn read_csv(path: &Path) -> Result<Vec<Vec<String>>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_path(path)
+        .map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+
+    reader
+        .records()
+        .map(|record| {
+            record
+                .map(|record| record.iter().map(str::to_string).collect())
+                .map_err(|e| format!("Failed to read {:?}: {}", path, e))
+        })
+        .collect()
+}
+
+fn read_xlsx(path: &Path) -> Result<Vec<Vec<String>>, String> {
+    let mut workbook = calamine::open_workbook_auto(path)
+        .map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| format!("{:?} has no sheets", path))?
+        .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+
+    Ok(range
+        .rows()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_scan::SwitchNodes;
+    use crate::models::{ReviewStatus, SwitchType};
+    use crate::switch_defaults::{builtin_defaults, seeded_switch};
+
+    fn lever() -> SwitchData {
+        let nodes = SwitchNodes {
+            pretty_name: "Gear".to_string(),
+            switch_type: "lever".to_string(),
+            visual: Some("Lever_Gear".to_string()),
+            collider: None,
+        };
+        seeded_switch(
+            SwitchType::Lever,
+            &nodes,
+            &builtin_defaults(&SwitchType::Lever),
+        )
+    }
+
+    fn row(cells: &[(&str, &str)]) -> SheetRow {
+        SheetRow {
+            line: 2,
+            cells: cells
+                .iter()
+                .map(|(column, value)| (column.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_edited_cells() {
+        let patch = row(&[
+            ("key", "Gear"),
+            ("rawNodeName", "Lever_Gear"),
+            ("upperLimit", " 45.5 "),
+            ("lowerLimit", ""),
+            ("reviewStatus", "reviewed"),
+        ])
+        .to_patch(&lever())
+        .unwrap();
+        assert_eq!(patch.upper_limit, Some(45.5));
+        assert_eq!(patch.lower_limit, None);
+        assert_eq!(patch.review_status, Some(ReviewStatus::Reviewed));
+    }
+
+    #[test]
+    fn reports_every_invalid_cell() {
+        let error = row(&[
+            ("rawNodeName", "Lever_Flaps"),
+            ("upperLimit", "lots"),
+            ("movementAxis", "W"),
+        ])
+        .to_patch(&lever())
+        .unwrap_err();
+        assert!(error.contains("rawNodeName can't be changed"));
+        assert!(error.contains("upperLimit 'lots' is not a number"));
+        assert!(error.contains("movementAxis 'W' is not a valid value"));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::model_scan::{SwitchTag, SWITCH_TAGS};
+use crate::models::{SwitchData, SwitchType, SwitchTypeDefinition};
+use crate::switch_defaults;
+use crate::utils;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// Optional switch fields a type can allow or leave out through `allowedFields`
+pub const OPTIONAL_SWITCH_FIELDS: [&str; 5] = [
+    "positions",
+    "additionalAxes",
+    "axisCouplings",
+    "behavior",
+    "soundOverrides",
+];
+
+/// Layout of the workspace registry file. It only holds the user-defined entries;
+/// an entry with a built-in id replaces that built-in type.
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SwitchTypesFile {
+    #[serde(default)]
+    types: Vec<SwitchTypeDefinition>,
+}
+
+/// The built-in types, detected by the same node name tags as before the registry existed
+pub fn builtin_types() -> Vec<SwitchTypeDefinition> {
+    SwitchType::ALL
+        .into_iter()
+        .map(|switch_type| {
+            let id = switch_type.id().to_string();
+            let mut display_name = id.clone();
+            display_name[..1].make_ascii_uppercase();
+
+            SwitchTypeDefinition {
+                detection_patterns: SWITCH_TAGS
+                    .iter()
+                    .filter(|tag| tag.trim_start_matches('-').eq_ignore_ascii_case(&id))
+                    .map(|tag| tag.to_string())
+                    .collect(),
+                defaults: switch_defaults::builtin_defaults(&switch_type),
+                id: switch_type,
+                display_name,
+                allowed_fields: None,
+            }
+        })
+        .collect()
+}
+
+/// The user-defined entries stored in the workspace
+pub fn load_custom_types(app_handle: &AppHandle) -> Result<Vec<SwitchTypeDefinition>, String> {
+    let file_path = utils::build_workspace_file_path(app_handle, crate::SWITCH_TYPES_FILE)?;
+    let types_file: SwitchTypesFile = utils::deserialize_json(utils::load_json_file(&file_path)?)?;
+
+    Ok(types_file.types)
+}
+
+pub fn save_custom_types(
+    app_handle: &AppHandle,
+    types: Vec<SwitchTypeDefinition>,
+) -> Result<(), String> {
+    let file_path = utils::build_workspace_file_path(app_handle, crate::SWITCH_TYPES_FILE)?;
+    utils::save_json_file(
+        &file_path,
+        &utils::serialize_to_json(&SwitchTypesFile { types })?,
+    )
+}
+
+/// Every known switch type: the built-ins, replaced or extended by the workspace entries
+pub fn load_registry(app_handle: &AppHandle) -> Result<Vec<SwitchTypeDefinition>, String> {
+    Ok(merge_registry(load_custom_types(app_handle)?))
+}
+
+fn merge_registry(custom_types: Vec<SwitchTypeDefinition>) -> Vec<SwitchTypeDefinition> {
+    let mut registry = builtin_types();
+
+    for definition in custom_types {
+        match registry.iter_mut().find(|t| t.id == definition.id) {
+            Some(existing) => *existing = definition,
+            None => registry.push(definition),
+        }
+    }
+
+    registry
+}
+
+pub fn find<'a>(
+    registry: &'a [SwitchTypeDefinition],
+    switch_type: &SwitchType,
+) -> Option<&'a SwitchTypeDefinition> {
+    registry.iter().find(|t| &t.id == switch_type)
+}
+
+/// The node name tags of every registered type in the order they are tried, since the first
+/// matching tag wins. The built-in tags keep their original order ahead of custom ones, so
+/// existing models detect the same types as before; custom tags follow in registry order.
+/// `check_definition` refuses tags that an earlier tag would always shadow.
+pub fn detection_tags(registry: &[SwitchTypeDefinition]) -> Vec<SwitchTag> {
+    let mut tags: Vec<SwitchTag> = registry
+        .iter()
+        .flat_map(|definition| {
+            definition.detection_patterns.iter().map(|tag| SwitchTag {
+                tag: tag.clone(),
+                switch_type: definition.id.id().to_string(),
+            })
+        })
+        .collect();
+    tags.sort_by_key(|t| {
+        SWITCH_TAGS
+            .iter()
+            .position(|builtin| builtin.eq_ignore_ascii_case(&t.tag))
+            .unwrap_or(SWITCH_TAGS.len())
+    });
+
+    tags
+}
+
+/// Detection tags for the current workspace, for callers that only need to scan a model
+pub fn load_detection_tags(app_handle: &AppHandle) -> Result<Vec<SwitchTag>, String> {
+    Ok(detection_tags(&load_registry(app_handle)?))
+}
+
+/// Optional fields a switch actually uses, named as in `OPTIONAL_SWITCH_FIELDS`
+pub fn used_optional_fields(switch_data: &SwitchData) -> Vec<&'static str> {
+    let used = [
+        !switch_data.positions.is_empty(),
+        !switch_data.additional_axes.is_empty(),
+        !switch_data.axis_couplings.is_empty(),
+        switch_data.behavior != Default::default(),
+        switch_data.sound_overrides != Default::default(),
+    ];
+
+    OPTIONAL_SWITCH_FIELDS
+        .into_iter()
+        .zip(used)
+        .filter_map(|(field, used)| used.then_some(field))
+        .collect()
+}
+
+/// Problems with a definition about to be stored, checked against the rest of the registry
+pub fn check_definition(
+    definition: &SwitchTypeDefinition,
+    registry: &[SwitchTypeDefinition],
+) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let id = definition.id.id();
+    if id.trim().is_empty() {
+        problems.push("type id is empty".to_string());
+    } else if !id
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+    {
+        problems.push(format!(
+            "type id '{}' may only use lowercase letters, digits, '_' and '-'",
+            id
+        ));
+    }
+
+    if definition.display_name.trim().is_empty() {
+        problems.push("display name is empty".to_string());
+    }
+
+    if definition.defaults.lower_limit > definition.defaults.upper_limit {
+        problems.push("lower limit must not be greater than upper limit".to_string());
+    }
+
+    for field in definition.allowed_fields.iter().flatten() {
+        if !OPTIONAL_SWITCH_FIELDS.contains(&field.as_str()) {
+            problems.push(format!(
+                "'{}' is not an optional switch field, expected one of {:?}",
+                field, OPTIONAL_SWITCH_FIELDS
+            ));
+        }
+    }
+
+    for tag in &definition.detection_patterns {
+        if tag.trim().is_empty() {
+            problems.push("detection patterns cannot be empty".to_string());
+            continue;
+        }
+
+        let taken_by = registry.iter().find(|other| {
+            other.id != definition.id
+                && other
+                    .detection_patterns
+                    .iter()
+                    .any(|t| t.eq_ignore_ascii_case(tag))
+        });
+        if let Some(other) = taken_by {
+            problems.push(format!(
+                "detection pattern '{}' is already used by '{}'",
+                tag,
+                other.id.id()
+            ));
+        }
+    }
+
+    problems.extend(shadowed_tags(definition, registry));
+
+    problems
+}
+
+/// Tags of `definition` that contain a tag of another type tried before them, e.g. "-LeverGuard"
+/// behind the built-in "-Lever". Such a tag could never match.
+fn shadowed_tags(
+    definition: &SwitchTypeDefinition,
+    registry: &[SwitchTypeDefinition],
+) -> Vec<String> {
+    let mut updated = registry.to_vec();
+    match updated.iter_mut().find(|t| t.id == definition.id) {
+        Some(existing) => *existing = definition.clone(),
+        None => updated.push(definition.clone()),
+    }
+
+    let id = definition.id.id();
+    let tags = detection_tags(&updated);
+    tags.iter()
+        .enumerate()
+        .filter(|(_, tag)| tag.switch_type == id)
+        .filter_map(|(index, tag)| {
+            let lowercase = tag.tag.to_lowercase();
+            let earlier = tags[..index].iter().find(|earlier| {
+                // Identical tags are already reported as taken
+                let earlier_tag = earlier.tag.to_lowercase();
+                earlier.switch_type != id
+                    && lowercase != earlier_tag
+                    && lowercase.contains(&earlier_tag)
+            })?;
+            Some(format!(
+                "detection pattern '{}' contains '{}' of '{}', which is tried first and would \
+                 always win",
+                tag.tag, earlier.tag, earlier.switch_type
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_type(id: &str, patterns: &[&str]) -> SwitchTypeDefinition {
+        SwitchTypeDefinition {
+            id: SwitchType::Custom(id.to_string()),
+            display_name: id.to_string(),
+            detection_patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            defaults: switch_defaults::builtin_defaults(&SwitchType::Lever),
+            allowed_fields: None,
+        }
+    }
+
+    #[test]
+    fn builtin_tags_are_tried_first() {
+        let registry = merge_registry(vec![custom_type("guard", &["-Guard"])]);
+        let order: Vec<String> = detection_tags(&registry)
+            .into_iter()
+            .map(|t| t.tag)
+            .collect();
+        assert_eq!(order, vec!["-Dial", "-Button", "-Lever", "-Guard"]);
+    }
+
+    #[test]
+    fn rejects_tags_shadowed_by_an_earlier_tag() {
+        let registry = builtin_types();
+        let problems = check_definition(&custom_type("lever_guard", &["-LeverGuard"]), &registry);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("'-Lever' of 'lever'"));
+
+        assert!(check_definition(&custom_type("guard", &["-Guard"]), &registry).is_empty());
+    }
+
+    #[test]
+    fn custom_tags_are_shadowed_by_earlier_custom_tags() {
+        let registry = merge_registry(vec![custom_type("guard", &["-Guard"])]);
+        let problems = check_definition(&custom_type("guard_cover", &["-GuardCover"]), &registry);
+        assert_eq!(problems.len(), 1);
+    }
+}
@@ -0,0 +1,832 @@
+use crate::models::{SwitchData, SwitchTypeDefinition, TeleportZone, DEFAULT_ZONE_DIMENSIONS};
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use serde_json::{json, Value as Json};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+pub const TYPES_HEADER_FILE: &str = "PlaneConfigTypes.h";
+pub const DESERIALIZER_FILE: &str = "PlaneConfigDeserializer.cpp";
+
+/// Enumerator used for switch type ids the generated code doesn't know about
+const UNKNOWN_SWITCH_TYPE: &str = "Unknown";
+
+/// `FSwitch` properties naming the composite control a switch is part of and its part name
+/// ("cover" or "guarded"), empty for standalone switches
+const COMPOSITE_CONTROL_FIELD: &str = "compositeControl";
+const COMPOSITE_ROLE_FIELD: &str = "compositeRole";
+
+#[derive(Clone, Copy, PartialEq)]
+enum CppType {
+    String,
+    Float,
+    Bool,
+    Vector,
+    Enum(&'static str),
+}
+
+/// One struct property and the config field it is read from
+struct CppField {
+    json: String,
+    name: String,
+    cpp_type: CppType,
+    default: String, // C++ initializer, empty for the type's own default
+    fallback: Option<&'static str>, // Property copied when the field is missing or null
+}
+
+/// `momentarySwitch` -> `bMomentarySwitch`, `rawNodeName` -> `RawNodeName`
+fn property_name(json: &str, cpp_type: CppType) -> String {
+    let mut name = json.to_string();
+    name[..1].make_ascii_uppercase();
+    if cpp_type == CppType::Bool {
+        name.insert(0, 'b');
+    }
+    name
+}
+
+/// The struct properties for every scalar field of a model, in declaration order, read from
+/// its JSON schema so they can't drift from models.rs. Lists and nested objects such as
+/// detents or the behavior block stay in the JSON export.
+fn schema_fields(root: RootSchema) -> Vec<CppField> {
+    let Some(object) = root.schema.object.as_ref() else {
+        return Vec::new();
+    };
+
+    object
+        .properties
+        .iter()
+        .filter_map(|(json, schema)| {
+            let cpp_type = property_type(schema, &root.definitions)?;
+            Some(CppField {
+                json: json.clone(),
+                name: property_name(json, cpp_type),
+                cpp_type,
+                default: String::new(),
+                fallback: None,
+            })
+        })
+        .collect()
+}
+
+/// The C++ type of a schema property, `None` for anything that isn't a scalar or a `Vec3`
+fn property_type(schema: &Schema, definitions: &schemars::Map<String, Schema>) -> Option<CppType> {
+    let Schema::Object(object) = schema else {
+        return None;
+    };
+
+    match schema_reference(object) {
+        Some(reference) => {
+            let name = reference.rsplit('/').next()?;
+            if name == "Vec3" {
+                return Some(CppType::Vector);
+            }
+            match definitions.get(name)? {
+                Schema::Object(definition) => scalar_type(definition),
+                Schema::Bool(_) => None,
+            }
+        }
+        None => scalar_type(object),
+    }
+}
+
+/// A `$ref`, also when wrapped to carry a description, a default or `null`
+fn schema_reference(object: &SchemaObject) -> Option<&String> {
+    object.reference.as_ref().or_else(|| {
+        let subschemas = object.subschemas.as_ref()?;
+        subschemas
+            .all_of
+            .iter()
+            .chain(subschemas.any_of.iter())
+            .flatten()
+            .find_map(|schema| match schema {
+                Schema::Object(inner) => inner.reference.as_ref(),
+                Schema::Bool(_) => None,
+            })
+    })
+}
+
+/// Strings (including string enums), numbers and booleans, optional ones included
+fn scalar_type(object: &SchemaObject) -> Option<CppType> {
+    let types = match object.instance_type.as_ref()? {
+        SingleOrVec::Single(instance_type) => vec![**instance_type],
+        SingleOrVec::Vec(instance_types) => instance_types.clone(),
+    };
+
+    match types.into_iter().find(|t| *t != InstanceType::Null)? {
+        InstanceType::String => Some(CppType::String),
+        InstanceType::Number | InstanceType::Integer => Some(CppType::Float),
+        InstanceType::Boolean => Some(CppType::Bool),
+        _ => None,
+    }
+}
+
+/// The `FSwitch` properties, from the `SwitchData` schema, plus the composite control
+/// reference for cover and guarded switches
+fn switch_fields() -> Vec<CppField> {
+    let mut fields = schema_fields(schemars::schema_for!(SwitchData));
+    for field in &mut fields {
+        if field.json == "switchType" {
+            field.cpp_type = CppType::Enum("ESwitchType");
+            field.default = format!("ESwitchType::{}", UNKNOWN_SWITCH_TYPE);
+        }
+    }
+    for json in [COMPOSITE_CONTROL_FIELD, COMPOSITE_ROLE_FIELD] {
+        fields.push(CppField {
+            json: json.to_string(),
+            name: property_name(json, CppType::String),
+            cpp_type: CppType::String,
+            default: String::new(),
+            fallback: None,
+        });
+    }
+    fields
+}
+
+/// The `FTeleportZone` properties, from the `TeleportZone` schema
+fn teleport_zone_fields() -> Vec<CppField> {
+    let mut fields = schema_fields(schemars::schema_for!(TeleportZone));
+    for field in &mut fields {
+        match field.json.as_str() {
+            "shape" => {
+                field.cpp_type = CppType::Enum("ETeleportZoneShape");
+                field.default = "ETeleportZoneShape::Box".to_string();
+            }
+            "dimensions" => {
+                field.default = format!(
+                    "FVector({:?}, {:?}, {:?})",
+                    DEFAULT_ZONE_DIMENSIONS.x, DEFAULT_ZONE_DIMENSIONS.y, DEFAULT_ZONE_DIMENSIONS.z
+                );
+            }
+            "spawnYaw" => field.fallback = Some("Yaw"),
+            _ => {}
+        }
+    }
+    fields
+}
+
+/// A generated UENUM: config id and C++ enumerator for each value
+struct CppEnum {
+    name: &'static str,
+    values: Vec<(String, String)>,
+    fallback: Option<&'static str>, // Extra first enumerator for unrecognised ids
+}
+
+impl CppEnum {
+    fn enumerator(&self, id: &str) -> &str {
+        self.values
+            .iter()
+            .find(|(value_id, _)| value_id == id)
+            .map(|(_, enumerator)| enumerator.as_str())
+            .or(self.fallback)
+            .unwrap_or(&self.values[0].1)
+    }
+
+    fn enumerators(&self) -> impl Iterator<Item = &str> {
+        self.fallback.into_iter().chain(
+            self.values
+                .iter()
+                .map(|(_, enumerator)| enumerator.as_str()),
+        )
+    }
+
+    /// `ESwitchType` -> `SwitchTypeFromString`
+    fn parser(&self) -> String {
+        format!("{}FromString", &self.name[1..])
+    }
+}
+
+/// Switch types come from the registry, so custom types get their own enumerator
+fn switch_type_enum(registry: &[SwitchTypeDefinition]) -> CppEnum {
+    let mut used: HashSet<String> = HashSet::from([UNKNOWN_SWITCH_TYPE.to_string()]);
+    let values = registry
+        .iter()
+        .map(|definition| {
+            let id = definition.id.id().to_string();
+            let base = cpp_identifier(&id);
+            let enumerator = (1..)
+                .map(|n| {
+                    if n == 1 {
+                        base.clone()
+                    } else {
+                        format!("{}{}", base, n)
+                    }
+                })
+                .find(|candidate| used.insert(candidate.clone()))
+                .unwrap();
+            (id, enumerator)
+        })
+        .collect();
+
+    CppEnum {
+        name: "ESwitchType",
+        values,
+        fallback: Some(UNKNOWN_SWITCH_TYPE),
+    }
+}
+
+fn teleport_zone_shape_enum() -> CppEnum {
+    CppEnum {
+        name: "ETeleportZoneShape",
+        values: [("box", "Box"), ("cylinder", "Cylinder"), ("point", "Point")]
+            .into_iter()
+            .map(|(id, enumerator)| (id.to_string(), enumerator.to_string()))
+            .collect(),
+        fallback: None,
+    }
+}
+
+/// `rotary-knob` -> `RotaryKnob`
+fn cpp_identifier(id: &str) -> String {
+    let identifier: String = id
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect();
+
+    match identifier.chars().next() {
+        None => "Custom".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("Type{}", identifier),
+        Some(_) => identifier,
+    }
+}
+
+const GENERATED_NOTICE: &str = "// Generated by the plane configurator from its config models.\n\
+                                // Do not edit; regenerate it when the config format changes.\n";
+
+fn cpp_type_name(cpp_type: CppType) -> &'static str {
+    match cpp_type {
+        CppType::String => "FString",
+        CppType::Float => "float",
+        CppType::Bool => "bool",
+        CppType::Vector => "FVector",
+        CppType::Enum(name) => name,
+    }
+}
+
+fn cpp_default(field: &CppField) -> String {
+    if !field.default.is_empty() {
+        return format!(" = {}", field.default);
+    }
+    match field.cpp_type {
+        CppType::String => String::new(),
+        CppType::Float => " = 0.f".to_string(),
+        CppType::Bool => " = false".to_string(),
+        CppType::Vector => " = FVector::ZeroVector".to_string(),
+        CppType::Enum(_) => String::new(),
+    }
+}
+
+/// `PlaneConfigTypes.h`: the enums and the DataTable-compatible row structs
+pub fn types_header(registry: &[SwitchTypeDefinition]) -> String {
+    let switch_types = switch_type_enum(registry);
+    let shapes = teleport_zone_shape_enum();
+
+    let mut out = String::new();
+    out.push_str(GENERATED_NOTICE);
+    out.push_str(
+        "\n#pragma once\n\n\
+         #include \"CoreMinimal.h\"\n\
+         #include \"Engine/DataTable.h\"\n\
+         #include \"PlaneConfigTypes.generated.h\"\n",
+    );
+
+    write_enum(&mut out, &switch_types);
+    write_enum(&mut out, &shapes);
+    write_struct(
+        &mut out,
+        "FTeleportZone",
+        "Teleport Zone",
+        &teleport_zone_fields(),
+    );
+    write_struct(&mut out, "FSwitch", "Switch", &switch_fields());
+
+    out
+}
+
+fn write_enum(out: &mut String, cpp_enum: &CppEnum) {
+    let _ = write!(
+        out,
+        "\nUENUM(BlueprintType)\nenum class {} : uint8\n{{\n",
+        cpp_enum.name
+    );
+    for enumerator in cpp_enum.enumerators() {
+        let _ = writeln!(out, "    {},", enumerator);
+    }
+    out.push_str("};\n");
+}
+
+fn write_struct(out: &mut String, name: &str, category: &str, fields: &[CppField]) {
+    let _ = write!(
+        out,
+        "\nUSTRUCT(BlueprintType)\nstruct {} : public FTableRowBase\n{{\n    GENERATED_BODY()\n",
+        name
+    );
+    for field in fields {
+        let _ = write!(
+            out,
+            "\n    UPROPERTY(EditAnywhere, BlueprintReadWrite, Category = \"{}\")\n    {} {}{};\n",
+            category,
+            cpp_type_name(field.cpp_type),
+            field.name,
+            cpp_default(field)
+        );
+    }
+    out.push_str("};\n");
+}
+
+/// `PlaneConfigDeserializer.cpp`: `Ufile_handling::DeserializePlaneConfig` reading a config
+/// file or its engine export into the generated structs. `Ufile_handling` is still declared by
+/// the project's file_handling.h, which must include `PlaneConfigTypes.h` instead of defining
+/// its own copies of the structs and enums. Cover and guarded switches are read from the
+/// export's composite controls, with their composite reference filled in.
+pub fn deserializer_source(registry: &[SwitchTypeDefinition]) -> String {
+    let switch_types = switch_type_enum(registry);
+    let shapes = teleport_zone_shape_enum();
+
+    let mut out = String::new();
+    out.push_str(GENERATED_NOTICE);
+    out.push_str(
+        "// file_handling.h declares Ufile_handling and must #include \"PlaneConfigTypes.h\" in place of\n\
+         // its own FSwitch, FTeleportZone and enum definitions, or they are defined twice.\n\
+         \n#include \"file_handling.h\"\n\
+         #include \"PlaneConfigTypes.h\"\n\
+         #include \"Misc/FileHelper.h\"\n\
+         #include \"Misc/Paths.h\"\n\
+         #include \"Serialization/JsonReader.h\"\n\
+         #include \"Serialization/JsonSerializer.h\"\n",
+    );
+
+    write_enum_parser(&mut out, &switch_types);
+    write_enum_parser(&mut out, &shapes);
+
+    out.push_str(
+        "\nstatic FVector ReadVector(const TSharedPtr<FJsonObject> &Object, const FString &Field, const FVector &Default)\n\
+         {\n\
+         \x20   const TSharedPtr<FJsonObject> *VectorJson = nullptr;\n\
+         \x20   if (!Object->TryGetObjectField(Field, VectorJson))\n\
+         \x20   {\n\
+         \x20       return Default;\n\
+         \x20   }\n\
+         \x20   return FVector((*VectorJson)->GetNumberField(TEXT(\"x\")), (*VectorJson)->GetNumberField(TEXT(\"y\")), (*VectorJson)->GetNumberField(TEXT(\"z\")));\n\
+         }\n",
+    );
+
+    write_reader(
+        &mut out,
+        "FTeleportZone",
+        "TeleportZone",
+        &teleport_zone_fields(),
+        &shapes,
+    );
+    write_reader(
+        &mut out,
+        "FSwitch",
+        "Switch",
+        &switch_fields(),
+        &switch_types,
+    );
+
+    out.push_str(DESERIALIZE_PLANE_CONFIG);
+    out
+}
+
+fn write_enum_parser(out: &mut String, cpp_enum: &CppEnum) {
+    let _ = write!(
+        out,
+        "\nstatic {} {}(const FString &Id)\n{{\n",
+        cpp_enum.name,
+        cpp_enum.parser()
+    );
+    for (id, enumerator) in &cpp_enum.values {
+        let _ = write!(
+            out,
+            "    if (Id == TEXT(\"{}\"))\n    {{\n        return {}::{};\n    }}\n",
+            id, cpp_enum.name, enumerator
+        );
+    }
+    let _ = write!(
+        out,
+        "    return {}::{};\n}}\n",
+        cpp_enum.name,
+        cpp_enum.enumerator("")
+    );
+}
+
+fn write_reader(
+    out: &mut String,
+    struct_name: &str,
+    name: &str,
+    fields: &[CppField],
+    cpp_enum: &CppEnum,
+) {
+    let _ = write!(
+        out,
+        "\nstatic {} Read{}(const TSharedPtr<FJsonObject> &Json)\n{{\n    {} Result;\n",
+        struct_name, name, struct_name
+    );
+
+    for field in fields {
+        let (json, property) = (&field.json, &field.name);
+        let (json_type, value) = match field.cpp_type {
+            CppType::Vector => {
+                let _ = writeln!(
+                    out,
+                    "    Result.{0} = ReadVector(Json, TEXT(\"{1}\"), Result.{0});",
+                    property, json
+                );
+                continue;
+            }
+            CppType::String => (
+                "String",
+                format!("Json->GetStringField(TEXT(\"{}\"))", json),
+            ),
+            CppType::Float => (
+                "Number",
+                format!("Json->GetNumberField(TEXT(\"{}\"))", json),
+            ),
+            CppType::Bool => ("Boolean", format!("Json->GetBoolField(TEXT(\"{}\"))", json)),
+            CppType::Enum(_) => (
+                "String",
+                format!(
+                    "{}(Json->GetStringField(TEXT(\"{}\")))",
+                    cpp_enum.parser(),
+                    json
+                ),
+            ),
+        };
+        let _ = write!(
+            out,
+            "    if (Json->HasTypedField<EJson::{}>(TEXT(\"{}\")))\n    {{\n        Result.{} = {};\n    }}\n",
+            json_type, json, property, value
+        );
+        if let Some(fallback) = field.fallback {
+            let _ = write!(
+                out,
+                "    else\n    {{\n        Result.{} = Result.{};\n    }}\n",
+                property, fallback
+            );
+        }
+    }
+
+    out.push_str("    return Result;\n}\n");
+}
+
+const DESERIALIZE_PLANE_CONFIG: &str = r#"
+void Ufile_handling::DeserializePlaneConfig(const FString &FilePath, FString &PlaneName, FString &PlaneModelPath, TMap<FString, FTeleportZone> &TeleportZones, TMap<FString, FSwitch> &Switches, ReadFileOutcome &OutputPins)
+{
+    FString LoadedString;
+    if (!FFileHelper::LoadFileToString(LoadedString, *FilePath))
+    {
+        OutputPins = ReadFileOutcome::Failure;
+        return;
+    }
+
+    TSharedPtr<FJsonObject> RootObject;
+    TSharedRef<TJsonReader<>> JsonReader = TJsonReaderFactory<>::Create(LoadedString);
+    if (!FJsonSerializer::Deserialize(JsonReader, RootObject) || !RootObject.IsValid())
+    {
+        OutputPins = ReadFileOutcome::Failure;
+        return;
+    }
+
+    if (RootObject->HasTypedField<EJson::String>(TEXT("planeName")))
+    {
+        PlaneName = RootObject->GetStringField(TEXT("planeName"));
+    }
+    if (RootObject->HasTypedField<EJson::String>(TEXT("modelPath")))
+    {
+        PlaneModelPath = RootObject->GetStringField(TEXT("modelPath"));
+    }
+
+    const TSharedPtr<FJsonObject> *TeleportZonesObject = nullptr;
+    if (RootObject->TryGetObjectField(TEXT("teleportZones"), TeleportZonesObject))
+    {
+        for (const auto &TeleportZonePair : (*TeleportZonesObject)->Values)
+        {
+            TSharedPtr<FJsonObject> TeleportZoneJson = TeleportZonePair.Value->AsObject();
+            if (TeleportZoneJson.IsValid())
+            {
+                TeleportZones.Add(TeleportZonePair.Key, ReadTeleportZone(TeleportZoneJson));
+            }
+        }
+    }
+
+    const TSharedPtr<FJsonObject> *SwitchesObject = nullptr;
+    if (RootObject->TryGetObjectField(TEXT("switches"), SwitchesObject))
+    {
+        for (const auto &SwitchPair : (*SwitchesObject)->Values)
+        {
+            TSharedPtr<FJsonObject> SwitchJson = SwitchPair.Value->AsObject();
+            if (SwitchJson.IsValid())
+            {
+                Switches.Add(SwitchPair.Key, ReadSwitch(SwitchJson));
+            }
+        }
+    }
+
+    // The engine export moves cover and guarded switches into their composite control
+    const TSharedPtr<FJsonObject> *CompositeControlsObject = nullptr;
+    if (RootObject->TryGetObjectField(TEXT("compositeControls"), CompositeControlsObject))
+    {
+        for (const auto &CompositePair : (*CompositeControlsObject)->Values)
+        {
+            TSharedPtr<FJsonObject> CompositeJson = CompositePair.Value->AsObject();
+            const TSharedPtr<FJsonObject> *PartsObject = nullptr;
+            if (!CompositeJson.IsValid() || !CompositeJson->TryGetObjectField(TEXT("parts"), PartsObject))
+            {
+                continue;
+            }
+            for (const auto &PartPair : (*PartsObject)->Values)
+            {
+                TSharedPtr<FJsonObject> PartJson = PartPair.Value->AsObject();
+                const TSharedPtr<FJsonObject> *SwitchJson = nullptr;
+                if (PartJson.IsValid() && PartJson->TryGetObjectField(TEXT("switch"), SwitchJson))
+                {
+                    FSwitch Switch = ReadSwitch(*SwitchJson);
+                    Switch.CompositeControl = CompositePair.Key;
+                    Switch.CompositeRole = PartPair.Key;
+                    Switches.Add(PartJson->GetStringField(TEXT("switchKey")), Switch);
+                }
+            }
+        }
+    }
+
+    OutputPins = ReadFileOutcome::Success;
+}
+"#;
+
+/// One DataTable row: the row name under `Name`, then each property in struct order
+pub type TableRow = Vec<(String, Json)>;
+
+pub struct DataTables {
+    pub switches: Vec<TableRow>,
+    pub teleport_zones: Vec<TableRow>,
+}
+
+/// DataTable rows for the switches and `teleportZones` sections of an engine export, each
+/// named after its key. The switches include the parts of composite controls.
+pub fn data_tables(export: &Json, registry: &[SwitchTypeDefinition]) -> DataTables {
+    let switch_types = switch_type_enum(registry);
+    let shapes = teleport_zone_shape_enum();
+
+    DataTables {
+        switches: table_rows(&all_switches(export), &switch_fields(), &switch_types),
+        teleport_zones: table_rows(&export["teleportZones"], &teleport_zone_fields(), &shapes),
+    }
+}
+
+/// The export's standalone switches together with the cover and guarded switches it keeps
+/// under `compositeControls`, each tagged with its composite control and part name
+fn all_switches(export: &Json) -> Json {
+    let mut switches = export["switches"].as_object().cloned().unwrap_or_default();
+
+    for (composite, control) in export["compositeControls"]
+        .as_object()
+        .into_iter()
+        .flatten()
+    {
+        for (role, part) in control["parts"].as_object().into_iter().flatten() {
+            let (Some(key), Some(switch)) =
+                (part["switchKey"].as_str(), part["switch"].as_object())
+            else {
+                continue;
+            };
+            let mut switch = switch.clone();
+            switch.insert(COMPOSITE_CONTROL_FIELD.to_string(), json!(composite));
+            switch.insert(COMPOSITE_ROLE_FIELD.to_string(), json!(role));
+            switches.insert(key.to_string(), Json::Object(switch));
+        }
+    }
+
+    Json::Object(switches)
+}
+
+fn table_rows(section: &Json, fields: &[CppField], cpp_enum: &CppEnum) -> Vec<TableRow> {
+    let Some(entries) = section.as_object() else {
+        return Vec::new();
+    };
+    let mut keys: Vec<&String> = entries.keys().collect();
+    keys.sort();
+
+    keys.into_iter()
+        .map(|key| {
+            let entry = &entries[key];
+            let mut row = vec![("Name".to_string(), Json::String(key.clone()))];
+            for field in fields {
+                let fallback = field
+                    .fallback
+                    .and_then(|name| fields.iter().find(|f| f.name == name));
+                let value = match (&entry[&field.json], fallback) {
+                    (Json::Null, Some(fallback)) => &entry[&fallback.json],
+                    (value, _) => value,
+                };
+                row.push((field.name.clone(), table_value(field, value, cpp_enum)));
+            }
+            row
+        })
+        .collect()
+}
+
+/// A property value the way Unreal's DataTable JSON import expects it
+fn table_value(field: &CppField, value: &Json, cpp_enum: &CppEnum) -> Json {
+    match field.cpp_type {
+        CppType::String => Json::String(value.as_str().unwrap_or_default().to_string()),
+        CppType::Float => json!(value.as_f64().unwrap_or_default()),
+        CppType::Bool => Json::Bool(value.as_bool().unwrap_or_default()),
+        CppType::Vector => json!({
+            "X": value["x"].as_f64().unwrap_or_default(),
+            "Y": value["y"].as_f64().unwrap_or_default(),
+            "Z": value["z"].as_f64().unwrap_or_default(),
+        }),
+        CppType::Enum(_) => Json::String(
+            cpp_enum
+                .enumerator(value.as_str().unwrap_or_default())
+                .to_string(),
+        ),
+    }
+}
+
+/// The same rows as a DataTable JSON array
+pub fn table_json(rows: &[TableRow]) -> Json {
+    Json::Array(
+        rows.iter()
+            .map(|row| Json::Object(row.iter().cloned().collect()))
+            .collect(),
+    )
+}
+
+/// The same rows as DataTable CSV, with vectors written as `(X=..,Y=..,Z=..)`
+pub fn table_csv(rows: &[TableRow]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    if let Some(first) = rows.first() {
+        writer
+            .write_record(first.iter().map(|(name, _)| name))
+            .map_err(|e| format!("Failed to write DataTable CSV: {}", e))?;
+    }
+    for row in rows {
+        let record: Vec<String> = row.iter().map(|(_, value)| csv_cell(value)).collect();
+        writer
+            .write_record(&record)
+            .map_err(|e| format!("Failed to write DataTable CSV: {}", e))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to write DataTable CSV: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("Failed to write DataTable CSV: {}", e))
+}
+
+fn csv_cell(value: &Json) -> String {
+    match value {
+        Json::String(text) => text.clone(),
+        Json::Bool(true) => "True".to_string(),
+        Json::Bool(false) => "False".to_string(),
+        Json::Object(vector) => format!(
+            "(X={},Y={},Z={})",
+            number_text(&vector["X"]),
+            number_text(&vector["Y"]),
+            number_text(&vector["Z"])
+        ),
+        other => number_text(other),
+    }
+}
+
+/// Config values are single precision, so print them without the f64 widening noise
+fn number_text(value: &Json) -> String {
+    match value.as_f64() {
+        Some(number) => (number as f32).to_string(),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_scan::SwitchNodes;
+    use crate::models::{FullConfigFile, SwitchType, Vec3};
+    use crate::switch_defaults::{builtin_defaults, seeded_switch};
+    use crate::{switch_types, unreal_export};
+
+    /// Every scalar or vector key of a serialized model must have a struct property
+    fn assert_covered(serialized: Json, fields: &[CppField]) {
+        let Json::Object(entry) = serialized else {
+            panic!("models serialize to objects");
+        };
+        for (key, value) in entry {
+            let is_vector = value.get("x").is_some() && value.get("z").is_some();
+            if value.is_array() || (value.is_object() && !is_vector) {
+                continue;
+            }
+            let field = fields.iter().find(|field| field.json == key);
+            assert!(field.is_some(), "'{}' has no generated property", key);
+            if is_vector {
+                assert!(
+                    field.unwrap().cpp_type == CppType::Vector,
+                    "'{}' is a vector",
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn switch_fields_cover_switch_data() {
+        let nodes = SwitchNodes {
+            pretty_name: "Throttle".to_string(),
+            switch_type: "lever".to_string(),
+            visual: Some("Lever_Throttle".to_string()),
+            collider: None,
+        };
+        let switch = seeded_switch(
+            SwitchType::Lever,
+            &nodes,
+            &builtin_defaults(&SwitchType::Lever),
+        );
+        let fields = switch_fields();
+        assert_covered(serde_json::to_value(&switch).unwrap(), &fields);
+        assert!(fields.iter().any(|field| field.name == "bMomentarySwitch"));
+        assert!(fields.iter().all(|field| field.json != "positions"));
+    }
+
+    #[test]
+    fn switch_table_includes_composite_parts() {
+        let switch = |node: &str| {
+            let nodes = SwitchNodes {
+                pretty_name: node.to_string(),
+                switch_type: "lever".to_string(),
+                visual: Some(node.to_string()),
+                collider: None,
+            };
+            let defaults = builtin_defaults(&SwitchType::Lever);
+            serde_json::to_value(seeded_switch(SwitchType::Lever, &nodes, &defaults)).unwrap()
+        };
+        let config: FullConfigFile = serde_json::from_value(json!({
+            "planeName": "Test",
+            "modelPath": "test.glb",
+            "teleportZones": {},
+            "switches": {
+                "Battery": switch("Lever_Battery"),
+                "FireCover": switch("Lever_FireCover"),
+                "Fire": switch("Lever_Fire"),
+            },
+            "compositeControls": {
+                "FireHandle": {
+                    "coverSwitch": "FireCover",
+                    "guardedSwitch": "Fire",
+                    "coverClosedPosition": 0.0,
+                    "coverOpenPosition": 90.0
+                }
+            }
+        }))
+        .unwrap();
+
+        let export = unreal_export::build_export(&config).unwrap();
+        let tables = data_tables(&export, &switch_types::builtin_types());
+
+        let cell = |row: &TableRow, name: &str| {
+            row.iter()
+                .find(|(column, _)| column == name)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+        let rows: Vec<(Json, Json, Json)> = tables
+            .switches
+            .iter()
+            .map(|row| {
+                (
+                    cell(row, "Name"),
+                    cell(row, "CompositeControl"),
+                    cell(row, "CompositeRole"),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (json!("Battery"), json!(""), json!("")),
+                (json!("Fire"), json!("FireHandle"), json!("guarded")),
+                (json!("FireCover"), json!("FireHandle"), json!("cover")),
+            ]
+        );
+    }
+
+    #[test]
+    fn teleport_zone_fields_cover_teleport_zone() {
+        let zone = TeleportZone::at(Vec3 {
+            x: 1.0,
+            y: 0.0,
+            z: 2.0,
+        });
+        let fields = teleport_zone_fields();
+        assert_covered(serde_json::to_value(&zone).unwrap(), &fields);
+        let spawn_yaw = fields
+            .iter()
+            .find(|field| field.json == "spawnYaw")
+            .unwrap();
+        assert_eq!(spawn_yaw.fallback, Some("Yaw"));
+    }
+}
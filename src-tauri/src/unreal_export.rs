@@ -0,0 +1,328 @@
+use crate::models::{
+    CompositeControl, FullConfigFile, Interlock, MotionUnit, PositionRange, SwitchData,
+    TeleportZone, Vec3,
+};
+use crate::utils;
+use serde_json::{json, Map, Value as Json};
+use std::collections::HashSet;
+
+/// Build the document the Unreal importer reads. It mirrors the config file in engine units, except that
+/// switches belonging to a composite control are emitted once, as parts of that control, and the
+/// editor-only node classification is left out.
+pub fn build_export(config: &FullConfigFile) -> Result<Json, String> {
+    let part_keys: HashSet<&String> = config
+        .composite_controls
+        .values()
+        .flat_map(|c| [&c.cover_switch, &c.guarded_switch])
+        .collect();
+
+    let mut switches = Map::new();
+    for (key, switch_data) in &config.switches {
+        if !part_keys.contains(key) {
+            switches.insert(key.clone(), export_switch(switch_data)?);
+        }
+    }
+
+    let mut composite_controls = Map::new();
+    for (name, composite) in &config.composite_controls {
+        composite_controls.insert(name.clone(), export_composite(name, composite, config)?);
+    }
+
+    Ok(json!({
+        "planeName": config.plane_name,
+        "modelPath": config.model_path,
+        "teleportZones": export_teleport_zones(config)?,
+        "teleportLinks": utils::serialize_to_json(&config.teleport_links)?,
+        "defaultSpawnZone": config.default_spawn_zone,
+        "crewStations": export_crew_stations(config),
+        "switches": switches,
+        "compositeControls": composite_controls,
+        "interlocks": export_interlocks(config)?,
+        "panels": utils::serialize_to_json(&config.panels)?,
+        "soundLibrary": export_sound_library(config)?,
+    }))
+}
+
+/// Zones with their position and dimensions in centimetres, the config stores them in metres
+fn export_teleport_zones(config: &FullConfigFile) -> Result<Json, String> {
+    let (_, factor) = engine_units(MotionUnit::Meters);
+    let to_engine = |v: Vec3| Vec3 {
+        x: v.x * factor,
+        y: v.y * factor,
+        z: v.z * factor,
+    };
+
+    let mut zones = Map::new();
+    for (name, zone) in &config.teleport_zones {
+        let converted = TeleportZone {
+            position: to_engine(zone.position),
+            dimensions: to_engine(zone.dimensions),
+            ..zone.clone()
+        };
+        zones.insert(name.clone(), utils::serialize_to_json(&converted)?);
+    }
+
+    Ok(Json::Object(zones))
+}
+
+/// Interlocks with their ranges in the engine units of the switch each range applies to.
+/// Named positions are left as they are, the exported switches keep their detent names.
+fn export_interlocks(config: &FullConfigFile) -> Result<Json, String> {
+    // Validation rejects references to missing switches, leave any that slipped through as they are
+    let factor = |switch_key: &String| {
+        config
+            .switches
+            .get(switch_key)
+            .map(|s| engine_units(s.units).1)
+            .unwrap_or(1.0)
+    };
+    let scale = |range: &mut PositionRange, factor: f32| {
+        range.min *= factor;
+        range.max *= factor;
+    };
+
+    let mut interlocks = Map::new();
+    for (name, interlock) in &config.interlocks {
+        let mut converted: Interlock = interlock.clone();
+        if let Some(range) = &mut converted.gated_range {
+            scale(range, factor(&interlock.switch));
+        }
+        for condition in &mut converted.conditions {
+            if let Some(range) = &mut condition.range {
+                scale(range, factor(&condition.switch));
+            }
+        }
+        interlocks.insert(name.clone(), utils::serialize_to_json(&converted)?);
+    }
+
+    Ok(Json::Object(interlocks))
+}
+
+/// Stations with their panels resolved, so the engine only needs the switch list
+fn export_crew_stations(config: &FullConfigFile) -> Json {
+    let stations: Map<String, Json> = config
+        .crew_stations
+        .iter()
+        .map(|(key, station)| {
+            let spawn_zone = station
+                .spawn_zone
+                .clone()
+                .or_else(|| station.zones.first().cloned());
+            let station_json = json!({
+                "displayName": station.display_name,
+                "zones": station.zones,
+                "spawnZone": spawn_zone,
+                "switches": station.reachable_switches(&config.panels),
+            });
+            (key.clone(), station_json)
+        })
+        .collect();
+    Json::Object(stations)
+}
+
+/// The sound library with every file path resolved, since the engine doesn't know where the
+/// model lives
+fn export_sound_library(config: &FullConfigFile) -> Result<Json, String> {
+    let mut library = Map::new();
+    for (name, asset) in &config.sound_library {
+        let mut exported = utils::serialize_to_json(asset)?;
+        let file_path = utils::resolve_asset_path(&config.model_path, &asset.file_path);
+        exported["filePath"] = json!(file_path.to_string_lossy());
+        library.insert(name.clone(), exported);
+    }
+
+    Ok(Json::Object(library))
+}
+
+/// Unreal works in degrees and centimetres. Returns the engine unit and the factor that
+/// converts a value in `units` to it.
+fn engine_units(units: MotionUnit) -> (MotionUnit, f32) {
+    match units {
+        MotionUnit::Degrees => (MotionUnit::Degrees, 1.0),
+        MotionUnit::Radians => (MotionUnit::Degrees, 180.0 / std::f32::consts::PI),
+        MotionUnit::Centimeters => (MotionUnit::Centimeters, 1.0),
+        MotionUnit::Meters => (MotionUnit::Centimeters, 100.0),
+    }
+}
+
+/// A copy of the switch with every axis value converted to engine units, and its behavior
+/// resolved so the engine doesn't need to know the fallbacks
+fn to_engine_units(switch_data: &SwitchData) -> SwitchData {
+    let mut converted = switch_data.clone();
+
+    let (units, factor) = engine_units(switch_data.units);
+    converted.units = units;
+    converted.bleed_margins *= factor;
+    converted.default_position *= factor;
+    converted.upper_limit *= factor;
+    converted.lower_limit *= factor;
+    converted.behavior.return_target = Some(
+        switch_data
+            .behavior
+            .return_target
+            .unwrap_or(switch_data.default_position)
+            * factor,
+    );
+    converted.behavior.return_speed = switch_data.behavior.return_speed.map(|s| s * factor);
+    (
+        converted.behavior.momentary_upper,
+        converted.behavior.momentary_lower,
+    ) = switch_data
+        .behavior
+        .momentary_directions(switch_data.momentary_switch);
+    for position in &mut converted.positions {
+        position.value *= factor;
+        position.snap_tolerance = position.snap_tolerance.map(|t| t * factor);
+    }
+
+    let mut factors = vec![factor];
+    for axis in &mut converted.additional_axes {
+        let (units, factor) = engine_units(axis.units);
+        axis.units = units;
+        axis.bleed_margins *= factor;
+        axis.default_position *= factor;
+        axis.upper_limit *= factor;
+        axis.lower_limit *= factor;
+        factors.push(factor);
+    }
+
+    // Validation rejects couplings to missing axes, leave any that slipped through as they are
+    let scale = |range: &mut PositionRange, factor: f32| {
+        range.min *= factor;
+        range.max *= factor;
+    };
+    for coupling in &mut converted.axis_couplings {
+        if let Some(&factor) = factors.get(coupling.when_axis) {
+            scale(&mut coupling.when_range, factor);
+        }
+        if let Some(&factor) = factors.get(coupling.limit_axis) {
+            scale(&mut coupling.limit_range, factor);
+        }
+    }
+
+    converted
+}
+
+/// A switch in engine units, plus an `axes` list with the primary axis first so the engine
+/// can treat single- and multi-axis controls the same way
+fn export_switch(switch_data: &SwitchData) -> Result<Json, String> {
+    let switch_data = &to_engine_units(switch_data);
+    let mut exported = utils::serialize_to_json(switch_data)?;
+
+    let mut axes = vec![json!({
+        "movementAxis": switch_data.movement_axis,
+        "motion": switch_data.motion,
+        "units": switch_data.units,
+        "discrete": switch_data.discrete,
+        "momentarySwitch": switch_data.momentary_switch,
+        "bleedMargins": switch_data.bleed_margins,
+        "defaultPosition": switch_data.default_position,
+        "upperLimit": switch_data.upper_limit,
+        "lowerLimit": switch_data.lower_limit,
+    })];
+    for axis in &switch_data.additional_axes {
+        axes.push(utils::serialize_to_json(axis)?);
+    }
+
+    let exported_obj = exported
+        .as_object_mut()
+        .ok_or_else(|| "Expected switch to serialize to an object".to_string())?;
+    exported_obj.remove("additionalAxes");
+    exported_obj.insert("axes".to_string(), Json::Array(axes));
+
+    Ok(exported)
+}
+
+fn export_composite(
+    name: &str,
+    composite: &CompositeControl,
+    config: &FullConfigFile,
+) -> Result<Json, String> {
+    let part = |switch_key: &String| -> Result<Json, String> {
+        let switch_data = config.switches.get(switch_key).ok_or_else(|| {
+            format!(
+                "Composite control '{}' references missing switch '{}'",
+                name, switch_key
+            )
+        })?;
+
+        Ok(json!({
+            "switchKey": switch_key,
+            "switch": export_switch(switch_data)?,
+        }))
+    };
+
+    // The cover positions are in the cover switch's units
+    let cover_factor = config
+        .switches
+        .get(&composite.cover_switch)
+        .map(|s| engine_units(s.units).1)
+        .unwrap_or(1.0);
+
+    Ok(json!({
+        "description": composite.description,
+        "coverClosedPosition": composite.cover_closed_position * cover_factor,
+        "coverOpenPosition": composite.cover_open_position * cover_factor,
+        "parts": {
+            "cover": part(&composite.cover_switch)?,
+            "guarded": part(&composite.guarded_switch)?,
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_scan::SwitchNodes;
+    use crate::models::SwitchType;
+    use crate::switch_defaults::{builtin_defaults, seeded_switch};
+
+    fn switch(switch_type: SwitchType, node: &str) -> Json {
+        let nodes = SwitchNodes {
+            pretty_name: node.to_string(),
+            switch_type: switch_type.id().to_string(),
+            visual: Some(node.to_string()),
+            collider: None,
+        };
+        let defaults = builtin_defaults(&switch_type);
+        serde_json::to_value(seeded_switch(switch_type, &nodes, &defaults)).unwrap()
+    }
+
+    #[test]
+    fn exports_interlocks_and_panels_in_engine_units() {
+        let mut button = switch(SwitchType::Button, "Button_Start");
+        button["units"] = json!("m");
+        button["upperLimit"] = json!(0.01);
+        let config: FullConfigFile = serde_json::from_value(json!({
+            "planeName": "Test",
+            "modelPath": "test.glb",
+            "teleportZones": {},
+            "switches": {
+                "Start": button,
+                "Master": switch(SwitchType::Lever, "Lever_Master"),
+            },
+            "panels": { "Overhead": { "displayName": "Overhead", "switches": ["Start", "Master"] } },
+            "interlocks": {
+                "StartNeedsMaster": {
+                    "switch": "Start",
+                    "gatedRange": { "min": 0.0, "max": 0.01 },
+                    "conditions": [{ "switch": "Master", "range": { "min": 10.0, "max": 20.0 } }]
+                }
+            }
+        }))
+        .unwrap();
+
+        let export = build_export(&config).unwrap();
+        let primary_axis = &export["switches"]["Master"]["axes"][0];
+        assert_eq!(primary_axis["discrete"], json!(false));
+
+        let interlock = &export["interlocks"]["StartNeedsMaster"];
+        assert_eq!(interlock["gatedRange"]["max"], json!(1.0));
+        // Degrees are already engine units
+        assert_eq!(interlock["conditions"][0]["range"]["max"], json!(20.0));
+        assert_eq!(
+            export["panels"]["Overhead"]["switches"],
+            json!(["Start", "Master"])
+        );
+    }
+}
@@ -35,6 +35,50 @@ pub fn build_config_file_path(app_handle: &AppHandle, file_name: &str) -> Result
     Ok(config_folder_path.join(file_name))
 }
 
+/// Build the path to a workspace-wide file that lives next to the config folder
+pub fn build_workspace_file_path(
+    app_handle: &AppHandle,
+    file_name: &str,
+) -> Result<PathBuf, String> {
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|_| "Couldn't find app data directory".to_string())?;
+
+    Ok(app_data_dir.join(file_name))
+}
+
+/// Build the path an engine export of a config file is written to
+pub fn build_export_file_path(app_handle: &AppHandle, file_name: &str) -> Result<PathBuf, String> {
+    let export_folder_path =
+        build_workspace_file_path(app_handle, crate::UNREAL_EXPORT_FOLDER_PATH)?;
+
+    // Ensure the export directory exists
+    if !export_folder_path.exists() {
+        std::fs::create_dir_all(&export_folder_path)
+            .map_err(|e| format!("Failed to create export directory: {}", e))?;
+    }
+
+    Ok(export_folder_path.join(file_name))
+}
+
+/// Build the path of a generated Unreal source file, shared by every config in the workspace
+pub fn build_unreal_source_file_path(
+    app_handle: &AppHandle,
+    file_name: &str,
+) -> Result<PathBuf, String> {
+    let source_folder_path =
+        build_workspace_file_path(app_handle, crate::UNREAL_SOURCE_FOLDER_PATH)?;
+
+    // Ensure the source directory exists
+    if !source_folder_path.exists() {
+        std::fs::create_dir_all(&source_folder_path)
+            .map_err(|e| format!("Failed to create source directory: {}", e))?;
+    }
+
+    Ok(source_folder_path.join(file_name))
+}
+
 /// Load and parse a JSON file
 pub fn load_json_file(file_path: &std::path::Path) -> Result<Json, String> {
     use serde_json::json;
@@ -98,11 +142,73 @@ pub fn serialize_to_json<T: Serialize>(value: &T) -> Result<Json, String> {
     serde_json::to_value(value).map_err(|e| format!("Failed to serialize to JSON: {}", e))
 }
 
-/// Get the current configuration file and load its JSON content
+/// Read the model path referenced by a config file
+pub fn get_model_path(json_data: &Json) -> Result<&str, String> {
+    json_data
+        .get("modelPath")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "modelPath not found in the config file".to_string())
+}
+
+/// Resolve a path stored in a config file. Relative paths are taken from the model's folder,
+/// so assets can travel together with the model.
+pub fn resolve_asset_path(model_path: &str, file_path: &str) -> PathBuf {
+    let path = std::path::Path::new(file_path);
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    std::path::Path::new(model_path)
+        .parent()
+        .map(|folder| folder.join(path))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Deserialize a top-level section, falling back to its default when it is missing
+pub fn read_section<T: Default + for<'de> Deserialize<'de>>(
+    json_data: &Json,
+    section_name: &str,
+) -> Result<T, String> {
+    match json_data.get(section_name) {
+        Some(section) => serde_json::from_value(section.clone())
+            .map_err(|e| format!("Failed to deserialize '{}': {}", section_name, e)),
+        None => Ok(T::default()),
+    }
+}
+
+/// Serialize a value and store it as a top-level section, replacing any previous content
+pub fn write_section<T: Serialize>(
+    json_data: &mut Json,
+    section_name: &str,
+    value: &T,
+) -> Result<(), String> {
+    let section = serialize_to_json(value)?;
+
+    json_data
+        .as_object_mut()
+        .ok_or_else(|| "Invalid JSON structure: expected an object at the root".to_string())?
+        .insert(section_name.to_string(), section);
+
+    Ok(())
+}
+
+/// Compile a node name pattern where `*` matches any run of characters and `?` a single one.
+/// Matching is case-insensitive and covers the whole node name.
+pub fn compile_node_pattern(pattern: &str) -> Result<regex::Regex, String> {
+    let escaped = regex::escape(pattern)
+        .replace(r"\*", ".*")
+        .replace(r"\?", ".");
+
+    regex::Regex::new(&format!("(?i)^{}$", escaped))
+        .map_err(|e| format!("Invalid node pattern '{}': {}", pattern, e))
+}
+
+/// Get the current configuration file and load its JSON content, migrated to the current schema
 pub fn get_current_config(app_handle: &AppHandle) -> Result<(String, PathBuf, Json), String> {
     let current_file = get_current_file_name(app_handle)?;
     let file_path = build_config_file_path(app_handle, &current_file)?;
-    let json_data = load_json_file(&file_path)?;
+    let mut json_data = load_json_file(&file_path)?;
+    crate::migration::migrate_config(&mut json_data)?;
 
     Ok((current_file, file_path, json_data))
 }
@@ -0,0 +1,1248 @@
+use crate::geometry::{ModelGeometry, ZoneVolume};
+use crate::models::{
+    CompositeControl, CrewStation, FullConfigFile, Interlock, MotionKind, MotionUnit,
+    PositionRange, SoundAsset, SwitchData, SwitchTypeDefinition, TeleportLink, TeleportZone,
+    TeleportZoneShape, ValidationIssue, ValidationSeverity,
+};
+use crate::switch_types;
+use crate::utils;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+const SWITCHES_SECTION: &str = "switches";
+const INTERLOCKS_SECTION: &str = "interlocks";
+const COMPOSITES_SECTION: &str = "compositeControls";
+const SOUND_LIBRARY_SECTION: &str = "soundLibrary";
+const TELEPORT_ZONES_SECTION: &str = "teleportZones";
+const TELEPORT_LINKS_SECTION: &str = "teleportLinks";
+const DEFAULT_SPAWN_ZONE_KEY: &str = "defaultSpawnZone";
+const CREW_STATIONS_SECTION: &str = "crewStations";
+
+/// Tolerance used when comparing positions, since limits are stored as f32
+const POSITION_EPSILON: f32 = 1e-4;
+
+/// How far a teleport zone may be off its floor, in metres, before it is reported
+const FLOOR_TOLERANCE: f32 = 0.02;
+
+/// Two zones closer than this are treated as sharing a position
+const DUPLICATE_ZONE_DISTANCE: f32 = 0.01;
+
+/// Furthest a cockpit control plausibly travels from zero, in the given units.
+/// Anything beyond this usually means the limits were typed in a different unit.
+fn plausible_travel(units: MotionUnit) -> f32 {
+    match units {
+        MotionUnit::Degrees => 360.0,
+        MotionUnit::Radians => std::f32::consts::TAU,
+        MotionUnit::Centimeters => 30.0,
+        MotionUnit::Meters => 0.3,
+    }
+}
+
+/// Check the whole config file, sorted so the output is stable between runs
+pub fn validate_config(config: &FullConfigFile) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    let mut keys: Vec<&String> = config.switches.keys().collect();
+    keys.sort();
+    for key in keys {
+        issues.extend(validate_switch(key, &config.switches[key]));
+        issues.extend(validate_sound_overrides(
+            key,
+            &config.switches[key],
+            &config.sound_library,
+        ));
+    }
+
+    let mut names: Vec<&String> = config.sound_library.keys().collect();
+    names.sort();
+    for name in names {
+        issues.extend(validate_sound_asset(name, &config.sound_library[name]));
+    }
+
+    let mut names: Vec<&String> = config.interlocks.keys().collect();
+    names.sort();
+    for name in names {
+        issues.extend(validate_interlock(
+            name,
+            &config.interlocks[name],
+            &config.switches,
+        ));
+    }
+    issues.extend(validate_interlock_cycles(&config.interlocks));
+
+    let mut names: Vec<&String> = config.teleport_links.keys().collect();
+    names.sort();
+    for name in names {
+        issues.extend(validate_teleport_link(
+            name,
+            &config.teleport_links[name],
+            &config.teleport_zones,
+        ));
+    }
+    issues.extend(validate_teleport_graph(config));
+
+    let mut names: Vec<&String> = config.crew_stations.keys().collect();
+    names.sort();
+    for name in names {
+        issues.extend(validate_crew_station(
+            name,
+            &config.crew_stations[name],
+            config,
+        ));
+    }
+
+    let mut names: Vec<&String> = config.composite_controls.keys().collect();
+    names.sort();
+    let mut part_owners: HashMap<&str, &str> = HashMap::new();
+    for name in names {
+        let composite = &config.composite_controls[name];
+        issues.extend(validate_composite(name, composite, &config.switches));
+
+        // A switch can only be a part of one composite control
+        for part in [&composite.cover_switch, &composite.guarded_switch] {
+            if let Some(owner) = part_owners.insert(part.as_str(), name.as_str()) {
+                issues.push(issue(
+                    ValidationSeverity::Error,
+                    COMPOSITES_SECTION,
+                    name,
+                    format!("switch '{}' is already part of '{}'", part, owner),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// Check a single switch entry on its own
+pub fn validate_switch(key: &str, switch_data: &SwitchData) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut report =
+        |severity, message: String| issues.push(issue(severity, SWITCHES_SECTION, key, message));
+
+    let (lower, upper) = (switch_data.lower_limit, switch_data.upper_limit);
+
+    if lower > upper {
+        report(
+            ValidationSeverity::Error,
+            format!("lower limit {} is above upper limit {}", lower, upper),
+        );
+    }
+
+    if !in_range(switch_data.default_position, lower, upper) {
+        report(
+            ValidationSeverity::Warning,
+            format!(
+                "default position {} is outside the limits {}..{}",
+                switch_data.default_position, lower, upper
+            ),
+        );
+    }
+
+    for (severity, message) in check_units(switch_data.motion, switch_data.units, lower, upper) {
+        report(severity, message);
+    }
+
+    // Detent positions
+    if !switch_data.positions.is_empty() {
+        let mut labels = HashSet::new();
+
+        for position in &switch_data.positions {
+            if !labels.insert(position.label.as_str()) {
+                report(
+                    ValidationSeverity::Error,
+                    format!("position label '{}' is used more than once", position.label),
+                );
+            }
+
+            if !in_range(position.value, lower, upper) {
+                report(
+                    ValidationSeverity::Error,
+                    format!(
+                        "position '{}' ({}) is outside the limits {}..{}",
+                        position.label, position.value, lower, upper
+                    ),
+                );
+            }
+
+            if position.snap_tolerance.is_some_and(|t| t < 0.0) {
+                report(
+                    ValidationSeverity::Error,
+                    format!(
+                        "position '{}' has a negative snap tolerance",
+                        position.label
+                    ),
+                );
+            }
+        }
+
+        if !switch_data
+            .positions
+            .iter()
+            .any(|p| (p.value - switch_data.default_position).abs() <= POSITION_EPSILON)
+        {
+            report(
+                ValidationSeverity::Error,
+                format!(
+                    "default position {} is not one of the switch's positions",
+                    switch_data.default_position
+                ),
+            );
+        }
+    }
+
+    // Spring-return and physical behavior
+    let behavior = &switch_data.behavior;
+
+    if !switch_data.momentary_switch && (behavior.momentary_upper || behavior.momentary_lower) {
+        report(
+            ValidationSeverity::Error,
+            "behavior marks a direction as momentary but the switch isn't momentary".to_string(),
+        );
+    }
+
+    if let Some(target) = behavior.return_target {
+        if !switch_data.momentary_switch {
+            report(
+                ValidationSeverity::Warning,
+                format!(
+                    "return target {} is ignored since the switch isn't momentary",
+                    target
+                ),
+            );
+        }
+        if !in_range(target, lower, upper) {
+            report(
+                ValidationSeverity::Error,
+                format!(
+                    "return target {} is outside the limits {}..{}",
+                    target, lower, upper
+                ),
+            );
+        }
+        if !switch_data.positions.is_empty()
+            && !switch_data
+                .positions
+                .iter()
+                .any(|p| (p.value - target).abs() <= POSITION_EPSILON)
+        {
+            report(
+                ValidationSeverity::Warning,
+                format!(
+                    "return target {} is not one of the switch's positions",
+                    target
+                ),
+            );
+        }
+    }
+
+    // Only check directions flagged explicitly. The legacy fallback makes both directions
+    // momentary, and a plain push button returning to its lower limit is fine.
+    let return_target = behavior
+        .return_target
+        .unwrap_or(switch_data.default_position);
+    if behavior.momentary_upper && return_target >= upper - POSITION_EPSILON {
+        report(
+            ValidationSeverity::Warning,
+            "momentary towards the upper limit, but it returns to the upper limit".to_string(),
+        );
+    }
+    if behavior.momentary_lower && return_target <= lower + POSITION_EPSILON {
+        report(
+            ValidationSeverity::Warning,
+            "momentary towards the lower limit, but it returns to the lower limit".to_string(),
+        );
+    }
+
+    if behavior.return_speed.is_some_and(|s| s <= 0.0) {
+        report(
+            ValidationSeverity::Error,
+            "return speed must be above zero".to_string(),
+        );
+    }
+    if behavior.friction.is_some_and(|f| !(0.0..=1.0).contains(&f)) {
+        report(
+            ValidationSeverity::Error,
+            "friction must be between 0 and 1".to_string(),
+        );
+    }
+    if behavior.resistance.is_some_and(|r| r < 0.0) {
+        report(
+            ValidationSeverity::Error,
+            "resistance cannot be negative".to_string(),
+        );
+    }
+
+    // Additional axes, numbered from 1 since the flat fields are axis 0
+    let mut seen_axes = vec![(
+        format!("{:?}", switch_data.movement_axis),
+        switch_data.motion,
+    )];
+    for (index, axis) in switch_data.additional_axes.iter().enumerate() {
+        let number = index + 1;
+
+        if axis.lower_limit > axis.upper_limit {
+            report(
+                ValidationSeverity::Error,
+                format!(
+                    "axis {}: lower limit {} is above upper limit {}",
+                    number, axis.lower_limit, axis.upper_limit
+                ),
+            );
+        }
+        if !in_range(axis.default_position, axis.lower_limit, axis.upper_limit) {
+            report(
+                ValidationSeverity::Warning,
+                format!(
+                    "axis {}: default position {} is outside the limits {}..{}",
+                    number, axis.default_position, axis.lower_limit, axis.upper_limit
+                ),
+            );
+        }
+
+        for (severity, message) in
+            check_units(axis.motion, axis.units, axis.lower_limit, axis.upper_limit)
+        {
+            report(severity, format!("axis {}: {}", number, message));
+        }
+
+        let identity = (format!("{:?}", axis.movement_axis), axis.motion);
+        if seen_axes.contains(&identity) {
+            report(
+                ValidationSeverity::Error,
+                format!(
+                    "axis {} repeats the same {:?} movement as an earlier axis",
+                    number, axis.movement_axis
+                ),
+            );
+        }
+        seen_axes.push(identity);
+    }
+
+    // Axis couplings
+    let axis_limits: Vec<(f32, f32)> = std::iter::once((lower, upper))
+        .chain(
+            switch_data
+                .additional_axes
+                .iter()
+                .map(|a| (a.lower_limit, a.upper_limit)),
+        )
+        .collect();
+    for coupling in &switch_data.axis_couplings {
+        let (Some(when_limits), Some(limit_limits)) = (
+            axis_limits.get(coupling.when_axis),
+            axis_limits.get(coupling.limit_axis),
+        ) else {
+            report(
+                ValidationSeverity::Error,
+                format!(
+                    "coupling between axis {} and {} references an axis that doesn't exist",
+                    coupling.when_axis, coupling.limit_axis
+                ),
+            );
+            continue;
+        };
+
+        if coupling.when_axis == coupling.limit_axis {
+            report(
+                ValidationSeverity::Error,
+                format!("axis {} cannot be coupled to itself", coupling.when_axis),
+            );
+        }
+
+        for message in check_range_within(&coupling.when_range, when_limits.0, when_limits.1) {
+            report(
+                ValidationSeverity::Error,
+                format!("coupling on axis {}: range {}", coupling.when_axis, message),
+            );
+        }
+        for message in check_range_within(&coupling.limit_range, limit_limits.0, limit_limits.1) {
+            report(
+                ValidationSeverity::Error,
+                format!(
+                    "coupling on axis {}: range {}",
+                    coupling.limit_axis, message
+                ),
+            );
+        }
+    }
+
+    issues
+}
+
+/// Check every switch against the workspace switch type registry
+pub fn validate_switch_types(
+    config: &FullConfigFile,
+    registry: &[SwitchTypeDefinition],
+) -> Vec<ValidationIssue> {
+    let mut keys: Vec<&String> = config.switches.keys().collect();
+    keys.sort();
+
+    keys.into_iter()
+        .flat_map(|key| validate_switch_type(key, &config.switches[key], registry))
+        .collect()
+}
+
+/// Check that a switch's type is registered and that it only uses the fields its type allows
+pub fn validate_switch_type(
+    key: &str,
+    switch_data: &SwitchData,
+    registry: &[SwitchTypeDefinition],
+) -> Vec<ValidationIssue> {
+    let Some(definition) = switch_types::find(registry, &switch_data.switch_type) else {
+        return vec![issue(
+            ValidationSeverity::Error,
+            SWITCHES_SECTION,
+            key,
+            format!(
+                "switch type '{}' is not in the switch type registry",
+                switch_data.switch_type.id()
+            ),
+        )];
+    };
+
+    let Some(allowed) = &definition.allowed_fields else {
+        return Vec::new();
+    };
+
+    switch_types::used_optional_fields(switch_data)
+        .into_iter()
+        .filter(|field| !allowed.iter().any(|a| a == field))
+        .map(|field| {
+            issue(
+                ValidationSeverity::Error,
+                SWITCHES_SECTION,
+                key,
+                format!(
+                    "'{}' is not allowed for {} switches",
+                    field, definition.display_name
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Check that every teleport zone stands on a floor of the model
+pub fn validate_zone_floors(
+    zones: &HashMap<String, TeleportZone>,
+    geometry: &ModelGeometry,
+) -> Vec<ValidationIssue> {
+    let mut names: Vec<&String> = zones.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let position = zones[name].position;
+
+            let (severity, message) = match geometry.floor_under(position) {
+                None => (
+                    ValidationSeverity::Error,
+                    "is not above any floor of the model".to_string(),
+                ),
+                Some(floor) if position.z - floor > FLOOR_TOLERANCE => (
+                    ValidationSeverity::Warning,
+                    format!("floats {:.3} above the floor", position.z - floor),
+                ),
+                Some(floor) if floor - position.z > FLOOR_TOLERANCE => (
+                    ValidationSeverity::Warning,
+                    format!("is sunk {:.3} into the floor", floor - position.z),
+                ),
+                Some(_) => return None,
+            };
+
+            Some(issue(severity, TELEPORT_ZONES_SECTION, name, message))
+        })
+        .collect()
+}
+
+/// Check teleport zones against the model's bounds, the switch colliders and each other
+pub fn validate_teleport_zones(
+    config: &FullConfigFile,
+    geometry: &ModelGeometry,
+) -> Vec<ValidationIssue> {
+    let mut names: Vec<&String> = config.teleport_zones.keys().collect();
+    names.sort();
+
+    let volumes: Vec<ZoneVolume> = names
+        .iter()
+        .map(|name| ZoneVolume::of(&config.teleport_zones[*name]))
+        .collect();
+
+    let mut colliders: Vec<(&String, &str)> = config
+        .switches
+        .iter()
+        .map(|(key, switch_data)| {
+            let node = switch_data
+                .collider_node_name
+                .as_deref()
+                .unwrap_or(&switch_data.raw_node_name);
+            (key, node)
+        })
+        .collect();
+    colliders.sort();
+
+    let mut issues = Vec::new();
+
+    for (index, name) in names.iter().enumerate() {
+        let zone = &config.teleport_zones[*name];
+        let volume = &volumes[index];
+
+        // Cylinders only use x (diameter) and z (height), points no dimensions at all
+        let used_dimensions = match zone.shape {
+            TeleportZoneShape::Box => {
+                &[zone.dimensions.x, zone.dimensions.y, zone.dimensions.z][..]
+            }
+            TeleportZoneShape::Cylinder => &[zone.dimensions.x, zone.dimensions.z][..],
+            TeleportZoneShape::Point => &[][..],
+        };
+        if used_dimensions.iter().any(|size| *size <= 0.0) {
+            issues.push(issue(
+                ValidationSeverity::Error,
+                TELEPORT_ZONES_SECTION,
+                name,
+                "dimensions must all be greater than zero".to_string(),
+            ));
+        }
+
+        if let Some(model_bounds) = geometry.bounds {
+            if !model_bounds.contains(zone.position) {
+                issues.push(issue(
+                    ValidationSeverity::Error,
+                    TELEPORT_ZONES_SECTION,
+                    name,
+                    "is outside the aircraft's bounds".to_string(),
+                ));
+            } else if !model_bounds.contains(volume.bounds().min)
+                || !model_bounds.contains(volume.bounds().max)
+            {
+                issues.push(issue(
+                    ValidationSeverity::Warning,
+                    TELEPORT_ZONES_SECTION,
+                    name,
+                    "extends past the aircraft's bounds".to_string(),
+                ));
+            }
+        }
+
+        for (other_index, other_name) in names.iter().enumerate().skip(index + 1) {
+            let other = &config.teleport_zones[*other_name];
+            let distance = ((zone.position.x - other.position.x).powi(2)
+                + (zone.position.y - other.position.y).powi(2)
+                + (zone.position.z - other.position.z).powi(2))
+            .sqrt();
+
+            if distance < DUPLICATE_ZONE_DISTANCE {
+                issues.push(issue(
+                    ValidationSeverity::Error,
+                    TELEPORT_ZONES_SECTION,
+                    name,
+                    format!("has the same position as zone '{}'", other_name),
+                ));
+            } else if volume.overlaps(&volumes[other_index]) {
+                issues.push(issue(
+                    ValidationSeverity::Warning,
+                    TELEPORT_ZONES_SECTION,
+                    name,
+                    format!("overlaps zone '{}'", other_name),
+                ));
+            }
+        }
+
+        for (switch_key, node) in &colliders {
+            let Some(collider) = geometry.node_bounds.get(*node) else {
+                continue;
+            };
+            if volume.bounds().intersects(collider) {
+                issues.push(issue(
+                    ValidationSeverity::Warning,
+                    TELEPORT_ZONES_SECTION,
+                    name,
+                    format!("intersects the collider of switch '{}'", switch_key),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+pub fn validate_teleport_link(
+    name: &str,
+    link: &TeleportLink,
+    zones: &HashMap<String, TeleportZone>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    for zone_name in [&link.from, &link.to] {
+        if !zones.contains_key(zone_name) {
+            issues.push(issue(
+                ValidationSeverity::Error,
+                TELEPORT_LINKS_SECTION,
+                name,
+                format!("references unknown teleport zone '{}'", zone_name),
+            ));
+        }
+    }
+
+    if link.from == link.to {
+        issues.push(issue(
+            ValidationSeverity::Error,
+            TELEPORT_LINKS_SECTION,
+            name,
+            "links a zone to itself".to_string(),
+        ));
+    }
+
+    issues
+}
+
+/// Check the default spawn zone and that every zone can be reached from it.
+/// Configs without any links allow free teleporting, so reachability is only checked once
+/// the graph has been started.
+fn validate_teleport_graph(config: &FullConfigFile) -> Vec<ValidationIssue> {
+    let Some(spawn) = &config.default_spawn_zone else {
+        if config.teleport_links.is_empty() {
+            return Vec::new();
+        }
+        return vec![issue(
+            ValidationSeverity::Warning,
+            TELEPORT_LINKS_SECTION,
+            DEFAULT_SPAWN_ZONE_KEY,
+            "no default spawn zone is set".to_string(),
+        )];
+    };
+
+    if !config.teleport_zones.contains_key(spawn) {
+        return vec![issue(
+            ValidationSeverity::Error,
+            TELEPORT_LINKS_SECTION,
+            DEFAULT_SPAWN_ZONE_KEY,
+            format!("references unknown teleport zone '{}'", spawn),
+        )];
+    }
+
+    if config.teleport_links.is_empty() {
+        return Vec::new();
+    }
+
+    let reachable = reachable_zones(spawn, &config.teleport_links);
+    let mut names: Vec<&String> = config.teleport_zones.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter(|name| !reachable.contains(name.as_str()))
+        .map(|name| {
+            issue(
+                ValidationSeverity::Warning,
+                TELEPORT_ZONES_SECTION,
+                name,
+                format!("cannot be reached from the spawn zone '{}'", spawn),
+            )
+        })
+        .collect()
+}
+
+fn reachable_zones<'a>(
+    start: &'a str,
+    links: &'a HashMap<String, TeleportLink>,
+) -> HashSet<&'a str> {
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for link in links.values() {
+        edges.entry(&link.from).or_default().push(&link.to);
+        if link.bidirectional {
+            edges.entry(&link.to).or_default().push(&link.from);
+        }
+    }
+
+    let mut reachable = HashSet::from([start]);
+    let mut pending = vec![start];
+    while let Some(zone) = pending.pop() {
+        for next in edges.get(zone).into_iter().flatten() {
+            if reachable.insert(next) {
+                pending.push(next);
+            }
+        }
+    }
+    reachable
+}
+
+pub fn validate_crew_station(
+    name: &str,
+    station: &CrewStation,
+    config: &FullConfigFile,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut error = |message: String| {
+        issues.push(issue(
+            ValidationSeverity::Error,
+            CREW_STATIONS_SECTION,
+            name,
+            message,
+        ))
+    };
+
+    for zone in &station.zones {
+        if !config.teleport_zones.contains_key(zone) {
+            error(format!("references unknown teleport zone '{}'", zone));
+        }
+    }
+    for key in &station.switches {
+        if !config.switches.contains_key(key) {
+            error(format!("references unknown switch '{}'", key));
+        }
+    }
+    for key in &station.panels {
+        if !config.panels.contains_key(key) {
+            error(format!("references unknown panel '{}'", key));
+        }
+    }
+    if let Some(spawn) = &station.spawn_zone {
+        if !station.zones.contains(spawn) {
+            error(format!(
+                "spawn zone '{}' is not one of the station's zones",
+                spawn
+            ));
+        }
+    }
+
+    if station.zones.is_empty() {
+        issues.push(issue(
+            ValidationSeverity::Warning,
+            CREW_STATIONS_SECTION,
+            name,
+            "has no teleport zones to spawn in".to_string(),
+        ));
+    }
+
+    issues
+}
+
+/// Check the values of a sound library entry, without touching the file system
+pub fn validate_sound_asset(name: &str, asset: &SoundAsset) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut report = |severity, message: String| {
+        issues.push(issue(severity, SOUND_LIBRARY_SECTION, name, message))
+    };
+
+    if asset.file_path.trim().is_empty() {
+        report(ValidationSeverity::Error, "file path is empty".to_string());
+    }
+    if asset.volume < 0.0 {
+        report(
+            ValidationSeverity::Error,
+            format!("volume {} cannot be negative", asset.volume),
+        );
+    }
+    if asset.pitch_variance < 0.0 {
+        report(
+            ValidationSeverity::Error,
+            format!("pitch variance {} cannot be negative", asset.pitch_variance),
+        );
+    } else if asset.pitch_variance > 1.0 {
+        report(
+            ValidationSeverity::Warning,
+            format!(
+                "pitch variance {} is more than the original pitch",
+                asset.pitch_variance
+            ),
+        );
+    }
+
+    issues
+}
+
+/// Check that a switch's library sounds point at library entries and its overrides at
+/// existing detents
+pub fn validate_sound_overrides(
+    key: &str,
+    switch_data: &SwitchData,
+    library: &HashMap<String, SoundAsset>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut report = |message: String| {
+        issues.push(issue(
+            ValidationSeverity::Error,
+            SWITCHES_SECTION,
+            key,
+            message,
+        ))
+    };
+
+    let overrides = &switch_data.sound_overrides;
+    let sound_effect = switch_data.sound_effect.library_asset().cloned();
+    let mut references: Vec<(String, &String)> = [
+        ("sound effect", &sound_effect),
+        ("sound", &overrides.asset),
+        ("sound towards the upper limit", &overrides.towards_upper),
+        ("sound towards the lower limit", &overrides.towards_lower),
+    ]
+    .into_iter()
+    .filter_map(|(what, asset)| asset.as_ref().map(|a| (what.to_string(), a)))
+    .collect();
+
+    let mut labels: Vec<&String> = overrides.positions.keys().collect();
+    labels.sort();
+    for label in labels {
+        if !switch_data.positions.iter().any(|p| &p.label == label) {
+            report(format!("sound override for unknown position '{}'", label));
+        }
+        references.push((
+            format!("sound for position '{}'", label),
+            &overrides.positions[label],
+        ));
+    }
+
+    for (what, asset) in references {
+        if !library.contains_key(asset) {
+            report(format!(
+                "{} uses '{}', which isn't in the sound library",
+                what, asset
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Check that every sound library file exists on disk
+pub fn validate_sound_files(config: &FullConfigFile) -> Vec<ValidationIssue> {
+    let mut names: Vec<&String> = config.sound_library.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter(|name| !config.sound_library[*name].file_path.trim().is_empty())
+        .filter_map(|name| {
+            let asset = &config.sound_library[name];
+            let path = utils::resolve_asset_path(&config.model_path, &asset.file_path);
+
+            (!path.is_file()).then(|| {
+                issue(
+                    ValidationSeverity::Error,
+                    SOUND_LIBRARY_SECTION,
+                    name,
+                    format!("audio file {:?} does not exist", path),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Check that an interlock only references switches and positions that exist
+pub fn validate_interlock(
+    name: &str,
+    interlock: &Interlock,
+    switches: &HashMap<String, SwitchData>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut report =
+        |severity, message: String| issues.push(issue(severity, INTERLOCKS_SECTION, name, message));
+
+    match switches.get(&interlock.switch) {
+        None => report(
+            ValidationSeverity::Error,
+            format!("gated switch '{}' does not exist", interlock.switch),
+        ),
+        Some(gated) => {
+            if let Some(range) = &interlock.gated_range {
+                for message in check_range(range, gated) {
+                    report(
+                        ValidationSeverity::Error,
+                        format!("gated range {}", message),
+                    );
+                }
+            }
+        }
+    }
+
+    if interlock.conditions.is_empty() {
+        report(
+            ValidationSeverity::Warning,
+            "has no conditions and never gates anything".to_string(),
+        );
+    }
+
+    for condition in &interlock.conditions {
+        if condition.switch == interlock.switch {
+            report(
+                ValidationSeverity::Error,
+                format!("switch '{}' cannot depend on itself", condition.switch),
+            );
+        }
+
+        let Some(other) = switches.get(&condition.switch) else {
+            report(
+                ValidationSeverity::Error,
+                format!("condition switch '{}' does not exist", condition.switch),
+            );
+            continue;
+        };
+
+        match (&condition.position, &condition.range) {
+            (Some(label), None) => {
+                if !other.positions.iter().any(|p| &p.label == label) {
+                    report(
+                        ValidationSeverity::Error,
+                        format!(
+                            "switch '{}' has no position named '{}'",
+                            condition.switch, label
+                        ),
+                    );
+                }
+            }
+            (None, Some(range)) => {
+                for message in check_range(range, other) {
+                    report(
+                        ValidationSeverity::Error,
+                        format!("condition on '{}': range {}", condition.switch, message),
+                    );
+                }
+            }
+            _ => report(
+                ValidationSeverity::Error,
+                format!(
+                    "condition on '{}' must set exactly one of position or range",
+                    condition.switch
+                ),
+            ),
+        }
+    }
+
+    issues
+}
+
+/// Check that a composite control links two distinct, existing switches
+pub fn validate_composite(
+    name: &str,
+    composite: &CompositeControl,
+    switches: &HashMap<String, SwitchData>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut report =
+        |severity, message: String| issues.push(issue(severity, COMPOSITES_SECTION, name, message));
+
+    if composite.cover_switch == composite.guarded_switch {
+        report(
+            ValidationSeverity::Error,
+            "cover and guarded switch must be different switches".to_string(),
+        );
+    }
+
+    if !switches.contains_key(&composite.guarded_switch) {
+        report(
+            ValidationSeverity::Error,
+            format!(
+                "guarded switch '{}' does not exist",
+                composite.guarded_switch
+            ),
+        );
+    }
+
+    match switches.get(&composite.cover_switch) {
+        None => report(
+            ValidationSeverity::Error,
+            format!("cover switch '{}' does not exist", composite.cover_switch),
+        ),
+        Some(cover) => {
+            for (label, value) in [
+                ("closed", composite.cover_closed_position),
+                ("open", composite.cover_open_position),
+            ] {
+                if !in_range(value, cover.lower_limit, cover.upper_limit) {
+                    report(
+                        ValidationSeverity::Error,
+                        format!(
+                            "cover {} position {} is outside the limits {}..{}",
+                            label, value, cover.lower_limit, cover.upper_limit
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Report each group of switches that depend on each other in a loop, once per group.
+/// Listing every individual loop instead would grow exponentially with the interlock count.
+fn validate_interlock_cycles(interlocks: &HashMap<String, Interlock>) -> Vec<ValidationIssue> {
+    // Sorted containers keep the reported groups deterministic
+    let mut depends_on: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for interlock in interlocks.values() {
+        for condition in &interlock.conditions {
+            // Self-references are already reported per interlock
+            if condition.switch != interlock.switch {
+                depends_on
+                    .entry(interlock.switch.as_str())
+                    .or_default()
+                    .insert(condition.switch.as_str());
+            }
+        }
+    }
+
+    strongly_connected(&depends_on)
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .map(|mut component| {
+            component.sort();
+            issue(
+                ValidationSeverity::Error,
+                INTERLOCKS_SECTION,
+                component[0],
+                format!("circular dependency between {}", component.join(", ")),
+            )
+        })
+        .collect()
+}
+
+/// Tarjan's strongly connected components, in the order they are completed
+fn strongly_connected<'a>(graph: &BTreeMap<&'a str, BTreeSet<&'a str>>) -> Vec<Vec<&'a str>> {
+    struct Search<'g, 'a> {
+        graph: &'g BTreeMap<&'a str, BTreeSet<&'a str>>,
+        index: HashMap<&'a str, usize>,
+        low_link: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: HashSet<&'a str>,
+        components: Vec<Vec<&'a str>>,
+    }
+
+    impl<'a> Search<'_, 'a> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.low_link.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            for &next in self.graph.get(node).into_iter().flatten() {
+                if !self.index.contains_key(next) {
+                    self.visit(next);
+                    let low = self.low_link[node].min(self.low_link[next]);
+                    self.low_link.insert(node, low);
+                } else if self.on_stack.contains(next) {
+                    let low = self.low_link[node].min(self.index[next]);
+                    self.low_link.insert(node, low);
+                }
+            }
+
+            if self.low_link[node] == index {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut search = Search {
+        graph,
+        index: HashMap::new(),
+        low_link: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        components: Vec::new(),
+    };
+    for &node in graph.keys() {
+        if !search.index.contains_key(node) {
+            search.visit(node);
+        }
+    }
+    search.components
+}
+
+/// Check that the units fit the motion and the limits are plausible in those units
+fn check_units(
+    motion: MotionKind,
+    units: MotionUnit,
+    lower: f32,
+    upper: f32,
+) -> Vec<(ValidationSeverity, String)> {
+    let mut problems = Vec::new();
+
+    if units.motion() != motion {
+        problems.push((
+            ValidationSeverity::Error,
+            format!("{:?} units don't fit {:?} motion", units, motion),
+        ));
+    }
+
+    let travel = plausible_travel(units);
+    if lower.abs() > travel || upper.abs() > travel {
+        problems.push((
+            ValidationSeverity::Warning,
+            format!(
+                "limits {}..{} are implausible in {:?}, expected within ±{}",
+                lower, upper, units, travel
+            ),
+        ));
+    }
+
+    problems
+}
+
+/// Problems with a range relative to a switch's primary limits, empty when the range is fine
+fn check_range(range: &PositionRange, switch_data: &SwitchData) -> Vec<String> {
+    check_range_within(range, switch_data.lower_limit, switch_data.upper_limit)
+}
+
+fn check_range_within(range: &PositionRange, lower: f32, upper: f32) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if range.min > range.max {
+        problems.push(format!("{}..{} has min above max", range.min, range.max));
+    }
+    if !in_range(range.min, lower, upper) || !in_range(range.max, lower, upper) {
+        problems.push(format!(
+            "{}..{} is outside the limits {}..{}",
+            range.min, range.max, lower, upper
+        ));
+    }
+
+    problems
+}
+
+/// Fail with a readable message if a switch has any error-level issue
+pub fn ensure_valid_switch(
+    key: &str,
+    switch_data: &SwitchData,
+    registry: &[SwitchTypeDefinition],
+) -> Result<(), String> {
+    let errors: Vec<String> = validate_switch(key, switch_data)
+        .into_iter()
+        .chain(validate_switch_type(key, switch_data, registry))
+        .filter(|issue| issue.severity == ValidationSeverity::Error)
+        .map(|issue| issue.message)
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Switch '{}' is invalid: {}",
+            key,
+            errors.join("; ")
+        ))
+    }
+}
+
+pub fn ensure_valid_teleport_zones(
+    config: &FullConfigFile,
+    geometry: &ModelGeometry,
+) -> Result<(), String> {
+    let errors: Vec<String> = validate_teleport_zones(config, geometry)
+        .into_iter()
+        .filter(|issue| issue.severity == ValidationSeverity::Error)
+        .map(|issue| format!("'{}' {}", issue.key, issue.message))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Teleport zones are invalid: {}", errors.join("; ")))
+    }
+}
+
+fn issue(
+    severity: ValidationSeverity,
+    section: &str,
+    key: &str,
+    message: String,
+) -> ValidationIssue {
+    ValidationIssue {
+        severity,
+        section: section.to_string(),
+        key: key.to_string(),
+        message,
+    }
+}
+
+fn in_range(value: f32, lower: f32, upper: f32) -> bool {
+    value >= lower - POSITION_EPSILON && value <= upper + POSITION_EPSILON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::InterlockCondition;
+
+    /// One interlock per edge, gating `from` on `to`
+    fn interlocks(edges: &[(&str, &str)]) -> HashMap<String, Interlock> {
+        edges
+            .iter()
+            .enumerate()
+            .map(|(index, (from, to))| {
+                let interlock = Interlock {
+                    switch: from.to_string(),
+                    description: String::new(),
+                    gated_range: None,
+                    conditions: vec![InterlockCondition {
+                        switch: to.to_string(),
+                        position: None,
+                        range: None,
+                    }],
+                };
+                (format!("Interlock{}", index), interlock)
+            })
+            .collect()
+    }
+
+    fn cycle_messages(edges: &[(&str, &str)]) -> Vec<(String, String)> {
+        validate_interlock_cycles(&interlocks(edges))
+            .into_iter()
+            .map(|issue| {
+                assert_eq!(issue.severity, ValidationSeverity::Error);
+                (issue.key, issue.message)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_a_loop_once() {
+        assert_eq!(
+            cycle_messages(&[("b", "c"), ("c", "a"), ("a", "b")]),
+            vec![(
+                "a".to_string(),
+                "circular dependency between a, b, c".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn reports_loops_sharing_a_switch_as_one_group() {
+        let found = cycle_messages(&[("a", "b"), ("b", "a"), ("b", "c"), ("c", "b"), ("d", "e")]);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, "circular dependency between a, b, c");
+    }
+
+    #[test]
+    fn reports_separate_loops_separately() {
+        let mut found = cycle_messages(&[("a", "b"), ("b", "a"), ("x", "y"), ("y", "x")]);
+        found.sort();
+        let keys: Vec<&str> = found.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "x"]);
+    }
+
+    #[test]
+    fn ignores_chains_and_self_references() {
+        assert!(cycle_messages(&[("a", "b"), ("b", "c"), ("a", "c"), ("c", "c")]).is_empty());
+    }
+
+    #[test]
+    fn densely_interlocked_switches_stay_cheap() {
+        // Every switch depends on every other, which has far too many loops to list one by one
+        let names: Vec<String> = (0..30).map(|i| format!("Switch{:02}", i)).collect();
+        let edges: Vec<(&str, &str)> = names
+            .iter()
+            .flat_map(|from| names.iter().map(move |to| (from.as_str(), to.as_str())))
+            .filter(|(from, to)| from != to)
+            .collect();
+        assert_eq!(cycle_messages(&edges).len(), 1);
+    }
+}
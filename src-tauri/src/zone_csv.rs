@@ -0,0 +1,105 @@
+use crate::models::{
+    NewTeleportZoneSubmission, TeleportZone, TeleportZoneProperties, TeleportZoneShape, Vec3,
+    DEFAULT_ZONE_DIMENSIONS,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// One spreadsheet row. Only the name and position are required; empty cells fall back
+/// to the same defaults as `add_new_teleport_zone`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ZoneRow {
+    name: String,
+    x: f32,
+    y: f32,
+    z: f32,
+    #[serde(default)]
+    yaw: Option<f32>,
+    #[serde(default)]
+    shape: Option<TeleportZoneShape>,
+    #[serde(default)]
+    size_x: Option<f32>,
+    #[serde(default)]
+    size_y: Option<f32>,
+    #[serde(default)]
+    size_z: Option<f32>,
+    #[serde(default)]
+    spawn_yaw: Option<f32>,
+    #[serde(default)]
+    snap_to_floor: Option<bool>,
+}
+
+impl ZoneRow {
+    fn into_submission(self) -> NewTeleportZoneSubmission {
+        let dimensions = (self.size_x.is_some() || self.size_y.is_some() || self.size_z.is_some())
+            .then(|| Vec3 {
+                x: self.size_x.unwrap_or(DEFAULT_ZONE_DIMENSIONS.x),
+                y: self.size_y.unwrap_or(DEFAULT_ZONE_DIMENSIONS.y),
+                z: self.size_z.unwrap_or(DEFAULT_ZONE_DIMENSIONS.z),
+            });
+
+        NewTeleportZoneSubmission {
+            teleport_zone_name: self.name,
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            properties: TeleportZoneProperties {
+                yaw: self.yaw,
+                shape: self.shape,
+                dimensions,
+                spawn_yaw: self.spawn_yaw,
+            },
+            snap_to_floor: self.snap_to_floor.unwrap_or(false),
+        }
+    }
+
+    fn from_zone(name: &str, zone: &TeleportZone) -> Self {
+        ZoneRow {
+            name: name.to_string(),
+            x: zone.position.x,
+            y: zone.position.y,
+            z: zone.position.z,
+            yaw: Some(zone.yaw),
+            shape: Some(zone.shape),
+            size_x: Some(zone.dimensions.x),
+            size_y: Some(zone.dimensions.y),
+            size_z: Some(zone.dimensions.z),
+            spawn_yaw: zone.spawn_yaw,
+            snap_to_floor: None,
+        }
+    }
+}
+
+/// Read every row of a zone CSV. Rows that fail to parse come back as errors, which name
+/// their line, so one bad row doesn't hide the others.
+pub fn read_zones(path: &Path) -> Result<Vec<Result<NewTeleportZoneSubmission, String>>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_path(path)
+        .map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+
+    Ok(reader
+        .deserialize::<ZoneRow>()
+        .map(|row| row.map(ZoneRow::into_submission).map_err(|e| e.to_string()))
+        .collect())
+}
+
+/// Write zones sorted by name, in the same layout `read_zones` accepts
+pub fn write_zones(path: &Path, zones: &HashMap<String, TeleportZone>) -> Result<(), String> {
+    let mut writer =
+        csv::Writer::from_path(path).map_err(|e| format!("Failed to create {:?}: {}", path, e))?;
+
+    let mut names: Vec<&String> = zones.keys().collect();
+    names.sort();
+    for name in names {
+        writer
+            .serialize(ZoneRow::from_zone(name, &zones[name]))
+            .map_err(|e| format!("Failed to write zone '{}': {}", name, e))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|e| format!("Failed to write {:?}: {}", path, e))
+}
@@ -5,29 +5,125 @@ export interface FullConfigFile {
   planeName: string;
   modelPath: string;
   teleportZones: {
-    [key: string]: {
-      x: number;
-      y: number;
-      z: number;
-    };
+    [key: string]: TeleportZone;
   };
   switches: {
     [key: string]: SwitchData;
   };
+  soundLibrary?: {
+    [key: string]: SoundAsset;
+  };
+  teleportLinks?: {
+    [key: string]: TeleportLink;
+  };
+  defaultSpawnZone?: string | null;
+  crewStations?: {
+    [key: string]: CrewStation;
+  };
+}
+
+export interface CrewStation {
+  displayName: string;
+  description?: string;
+  zones?: string[];
+  spawnZone?: string | null;
+  switches?: string[];
+  panels?: string[];
+}
+
+export interface TeleportLink {
+  from: string;
+  to: string;
+  bidirectional?: boolean;
+  description?: string;
 }
 
+interface Vec3 {
+  x: number;
+  y: number;
+  z: number;
+}
+
+export interface TeleportZone {
+  position: Vec3;
+  yaw: number;
+  shape: "box" | "cylinder" | "point";
+  dimensions: Vec3;
+  spawnYaw?: number | null;
+}
+
+interface SoundAsset {
+  filePath: string;
+  volume: number;
+  pitchVariance: number;
+}
+
+type MotionKind = "rotate" | "translate";
+type MotionUnit = "degrees" | "radians" | "cm" | "m";
+
 interface SwitchData {
   switchType: string;
   switchDescription: string;
   movementAxis: string;
   soundEffect: string;
-  movementMode: boolean;
+  motion: MotionKind;
+  units: MotionUnit;
+  discrete?: boolean;
   momentarySwitch: boolean;
   bleedMargins: number;
   defaultPosition: number;
   upperLimit: number;
   lowerLimit: number;
   rawNodeName: string;
+  visualNodeName: string | null;
+  colliderNodeName: string | null;
+  reviewStatus?: "reviewed" | "unreviewed";
+  positions?: DetentPosition[];
+  additionalAxes?: AxisConfig[];
+  axisCouplings?: AxisCoupling[];
+  behavior?: SwitchBehavior;
+  soundOverrides?: SwitchSoundOverrides;
+}
+
+interface SwitchSoundOverrides {
+  asset?: string | null;
+  towardsUpper?: string | null;
+  towardsLower?: string | null;
+  positions?: { [label: string]: string };
+}
+
+interface SwitchBehavior {
+  momentaryUpper?: boolean;
+  momentaryLower?: boolean;
+  returnTarget?: number | null;
+  returnSpeed?: number | null;
+  friction?: number | null;
+  resistance?: number | null;
+}
+
+interface DetentPosition {
+  label: string;
+  value: number;
+  snapTolerance?: number | null;
+}
+
+interface AxisConfig {
+  movementAxis: string;
+  motion: MotionKind;
+  units: MotionUnit;
+  discrete?: boolean;
+  momentarySwitch: boolean;
+  bleedMargins: number;
+  defaultPosition: number;
+  upperLimit: number;
+  lowerLimit: number;
+}
+
+interface AxisCoupling {
+  whenAxis: number;
+  whenRange: { min: number; max: number };
+  limitAxis: number;
+  limitRange: { min: number; max: number };
 }
 
 async function fetchSelectedConfigData(): Promise<FullConfigFile> {
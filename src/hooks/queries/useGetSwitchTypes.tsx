@@ -0,0 +1,33 @@
+import { useSuspenseQuery } from "@tanstack/react-query";
+import { invoke } from "@tauri-apps/api/core";
+
+export interface SwitchTypeDefinition {
+  id: string;
+  displayName: string;
+  detectionPatterns: string[];
+  defaults: {
+    movementAxis: string;
+    soundEffect: string;
+    motion: "rotate" | "translate";
+    units: "degrees" | "radians" | "cm" | "m";
+    discrete?: boolean;
+    momentarySwitch: boolean;
+    bleedMargins: number;
+    defaultPosition: number;
+    upperLimit: number;
+    lowerLimit: number;
+  };
+  allowedFields?: string[] | null;
+}
+
+async function fetchSwitchTypes(): Promise<SwitchTypeDefinition[]> {
+  const switch_types: SwitchTypeDefinition[] = await invoke("get_switch_types");
+  return switch_types;
+}
+
+export function useGetSwitchTypes() {
+  return useSuspenseQuery<SwitchTypeDefinition[]>({
+    queryKey: ["switch-types"],
+    queryFn: fetchSwitchTypes,
+  });
+}
@@ -6,6 +6,9 @@ export interface ParsedSwitchItem {
   prettyName: string;
   isConfigured: boolean;
   switchType: string;
+  visualNodeName: string | null;
+  colliderNodeName: string | null;
+  pairing: "paired" | "missingCollider" | "missingVisual";
 }
 
 export interface PlaneModelData {
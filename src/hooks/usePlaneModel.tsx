@@ -12,6 +12,8 @@ interface UsePlaneModelProps {
       prettyName: string;
       isConfigured: boolean;
       switchType: string;
+      visualNodeName: string | null;
+      colliderNodeName: string | null;
     }>;
   };
 }
@@ -42,6 +44,8 @@ export function usePlaneModel({ parsedData }: UsePlaneModelProps) {
             mesh,
             isConfigured: s.isConfigured,
             switchType: s.switchType,
+            visualNodeName: s.visualNodeName,
+            colliderNodeName: s.colliderNodeName,
           };
         }
         return null;
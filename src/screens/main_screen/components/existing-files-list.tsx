@@ -4,6 +4,8 @@ import { useGetSelectedFile } from "@/hooks/queries/useGetSelectedFile";
 import useSetCurrentFile from "@/hooks/mutations/useSetCurrentFile";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Label } from "@/components/ui/label";
 import { Plane, Check, Loader2 } from "lucide-react";
 import { invoke } from "@tauri-apps/api/core";
 import { open } from "@tauri-apps/plugin-dialog";
@@ -20,8 +22,10 @@ interface ExistingFilesListProps {
 interface NewFileEntryProps {
   fileName: string;
   modelPath: string;
+  seedFromModel: boolean;
   onFileNameChange: (fileName: string) => void;
   onModelPathChange: (modelPath: string) => void;
+  onSeedFromModelChange: (seedFromModel: boolean) => void;
   onSubmit: () => Promise<void>;
   onCancel: () => void;
 }
@@ -68,8 +72,10 @@ export function FileDropzone({ onFileSelect }: FileDropzoneProps) {
 function NewFileEntry({
   fileName,
   modelPath,
+  seedFromModel,
   onFileNameChange,
   onModelPathChange,
+  onSeedFromModelChange,
   onSubmit,
   onCancel,
 }: NewFileEntryProps) {
@@ -96,6 +102,16 @@ function NewFileEntry({
           Selected file: {modelPath}
         </p>
       )}
+      <div className="flex items-center space-x-2">
+        <Checkbox
+          id="seed-from-model"
+          checked={seedFromModel}
+          onCheckedChange={(checked) => onSeedFromModelChange(checked === true)}
+        />
+        <Label htmlFor="seed-from-model">
+          Generate starter switch configs from the model
+        </Label>
+      </div>
       <div className="flex items-center space-x-2">
         <Button onClick={handleSubmit} disabled={isSubmitting}>
           {isSubmitting ? "Creating..." : "Create"}
@@ -171,6 +187,7 @@ export function ExistingFilesList({
   const { data: selectedFile } = useGetSelectedFile();
   const [newFileName, setNewFileName] = useState("");
   const [newModelPath, setNewModelPath] = useState("");
+  const [seedFromModel, setSeedFromModel] = useState(false);
 
   const handleCreateNewFile = async () => {
     if (newFileName.trim() && newModelPath.trim()) {
@@ -178,9 +195,14 @@ export function ExistingFilesList({
       const planeName = newFileName.trim().replace(/\.json$/i, "");
       const modelFilePath = newModelPath.trim();
 
-      await invoke("create_new_config_file", { planeName, modelFilePath });
+      await invoke("create_new_config_file", {
+        planeName,
+        modelFilePath,
+        seedFromModel,
+      });
       setNewFileName("");
       setNewModelPath("");
+      setSeedFromModel(false);
       onNewFileCreated();
       refetch();
     }
@@ -194,8 +216,10 @@ export function ExistingFilesList({
           <NewFileEntry
             fileName={newFileName}
             modelPath={newModelPath}
+            seedFromModel={seedFromModel}
             onFileNameChange={setNewFileName}
             onModelPathChange={setNewModelPath}
+            onSeedFromModelChange={setSeedFromModel}
             onSubmit={handleCreateNewFile}
             onCancel={onNewFileCreated}
           />
@@ -15,6 +15,7 @@ import { Checkbox } from "@/components/ui/checkbox";
 import { Input } from "@/components/ui/input";
 import { Textarea } from "@/components/ui/textarea";
 import type { Control } from "react-hook-form";
+import { useGetSwitchTypes } from "@/hooks/queries/useGetSwitchTypes";
 
 interface FieldProps {
   control: Control<any>;
@@ -22,6 +23,8 @@ interface FieldProps {
 }
 
 export function SwitchTypeField({ control, disabled }: FieldProps) {
+  const { data: switchTypes } = useGetSwitchTypes();
+
   return (
     <FormField
       control={control}
@@ -29,15 +32,29 @@ export function SwitchTypeField({ control, disabled }: FieldProps) {
       render={({ field, fieldState: { error } }) => (
         <FormItem>
           <FormLabel>Switch Type</FormLabel>
-          <FormControl>
-            <Input
-              placeholder="Button"
-              {...field}
-              autoComplete="off"
-              disabled={true}
-              className="capitalize"
-            />
-          </FormControl>
+          <Select
+            value={field.value || ""}
+            onValueChange={field.onChange}
+            disabled={disabled}
+          >
+            <FormControl>
+              <SelectTrigger>
+                <SelectValue placeholder="Select switch type" />
+              </SelectTrigger>
+            </FormControl>
+            <SelectContent>
+              {switchTypes.map((switchType) => (
+                <SelectItem key={switchType.id} value={switchType.id}>
+                  {switchType.displayName}
+                </SelectItem>
+              ))}
+              {/* Keep values the registry doesn't know, e.g. a group selection */}
+              {field.value &&
+                !switchTypes.some((switchType) => switchType.id === field.value) && (
+                  <SelectItem value={field.value}>{field.value}</SelectItem>
+                )}
+            </SelectContent>
+          </Select>
           {error && (
             <p className="text-red-500 text-sm mt-1">{error.message}</p>
           )}
@@ -98,11 +115,77 @@ export function SwitchDescriptionField({ control, disabled }: FieldProps) {
   );
 }
 
-export function MovementModeField({ control, disabled }: FieldProps) {
+export function MotionField({ control, disabled }: FieldProps) {
+  return (
+    <FormField
+      control={control}
+      name="motion"
+      render={({ field, fieldState: { error } }) => (
+        <FormItem>
+          <FormLabel>Motion</FormLabel>
+          <Select
+            value={field.value || ""}
+            onValueChange={field.onChange}
+            disabled={disabled}
+          >
+            <FormControl>
+              <SelectTrigger>
+                <SelectValue placeholder="Select motion" />
+              </SelectTrigger>
+            </FormControl>
+            <SelectContent>
+              <SelectItem value="rotate">Rotate</SelectItem>
+              <SelectItem value="translate">Translate</SelectItem>
+            </SelectContent>
+          </Select>
+          {error && (
+            <p className="text-red-500 text-sm mt-1">{error.message}</p>
+          )}
+        </FormItem>
+      )}
+    />
+  );
+}
+
+export function UnitsField({ control, disabled }: FieldProps) {
+  return (
+    <FormField
+      control={control}
+      name="units"
+      render={({ field, fieldState: { error } }) => (
+        <FormItem>
+          <FormLabel>Units</FormLabel>
+          <Select
+            value={field.value || ""}
+            onValueChange={field.onChange}
+            disabled={disabled}
+          >
+            <FormControl>
+              <SelectTrigger>
+                <SelectValue placeholder="Select units" />
+              </SelectTrigger>
+            </FormControl>
+            <SelectContent>
+              <SelectItem value="degrees">Degrees</SelectItem>
+              <SelectItem value="radians">Radians</SelectItem>
+              <SelectItem value="cm">Centimetres</SelectItem>
+              <SelectItem value="m">Metres</SelectItem>
+            </SelectContent>
+          </Select>
+          {error && (
+            <p className="text-red-500 text-sm mt-1">{error.message}</p>
+          )}
+        </FormItem>
+      )}
+    />
+  );
+}
+
+export function DiscreteField({ control, disabled }: FieldProps) {
   return (
     <FormField
       control={control}
-      name="movementMode"
+      name="discrete"
       render={({ field, fieldState: { error } }) => (
         <FormItem>
           <FormLabel>Movement Mode</FormLabel>
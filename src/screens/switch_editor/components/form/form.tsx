@@ -14,7 +14,9 @@ import {
   SwitchTypeField,
   SwitchNameField,
   SwitchDescriptionField,
-  MovementModeField,
+  MotionField,
+  UnitsField,
+  DiscreteField,
   MovementAxisField,
   UpperLimitField,
   LowerLimitField,
@@ -40,7 +42,9 @@ function FormContent() {
       switchType: "button",
       movementAxis: "X",
       switchDescription: "",
-      movementMode: false,
+      motion: "translate",
+      units: "m",
+      discrete: false,
       momentarySwitch: false,
       defaultPosition: undefined,
       upperLimit: 0,
@@ -61,14 +65,12 @@ function FormContent() {
         switchType:
           selectedSwitches.length > 1
             ? "GROUP SELECTED"
-            : (primarySwitch.switchType as
-                | "button"
-                | "dial"
-                | "lever"
-                | "throttle"),
+            : existingConfig?.switchType ?? primarySwitch.switchType,
         movementAxis: (existingConfig?.movementAxis ?? "X") as "X" | "Y" | "Z",
         switchDescription: existingConfig?.switchDescription ?? "",
-        movementMode: existingConfig?.movementMode ?? false,
+        motion: existingConfig?.motion ?? "translate",
+        units: existingConfig?.units ?? "m",
+        discrete: existingConfig?.discrete ?? false,
         momentarySwitch: existingConfig?.momentarySwitch ?? false,
         defaultPosition: existingConfig?.defaultPosition,
         upperLimit: Number(existingConfig?.upperLimit ?? 0),
@@ -100,6 +102,8 @@ function FormContent() {
           switchName: sw.name,
           switchType: sw.switchType,
           rawNodeName: sw.mesh.name,
+          visualNodeName: sw.visualNodeName,
+          colliderNodeName: sw.colliderNodeName,
         }));
         createNewSwitch(batchedPayload as any);
       } else {
@@ -107,8 +111,9 @@ function FormContent() {
         createNewSwitch({
           ...validatedData,
           switchName: primarySwitch.name,
-          switchType: primarySwitch.switchType as any,
           rawNodeName: primarySwitch.mesh.name,
+          visualNodeName: primarySwitch.visualNodeName,
+          colliderNodeName: primarySwitch.colliderNodeName,
         });
       }
     } catch (error) {
@@ -135,18 +140,28 @@ function FormContent() {
             />
             <SwitchTypeField
               control={form.control}
-              disabled={!hasSelectedSwitches}
+              disabled={!hasSelectedSwitches || selectedSwitches.length > 1}
             />
           </div>
           <SwitchDescriptionField
             control={form.control}
             disabled={!hasSelectedSwitches}
           />
-          <MovementModeField
+          <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
+            <MotionField
+              control={form.control}
+              disabled={!hasSelectedSwitches}
+            />
+            <UnitsField
+              control={form.control}
+              disabled={!hasSelectedSwitches}
+            />
+          </div>
+          <MovementAxisField
             control={form.control}
             disabled={!hasSelectedSwitches}
           />
-          <MovementAxisField
+          <DiscreteField
             control={form.control}
             disabled={!hasSelectedSwitches}
           />
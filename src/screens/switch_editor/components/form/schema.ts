@@ -1,20 +1,20 @@
 import * as z from "zod"
 export const formSchema = z
   .object({
-    switchType: z.union([
-      z.enum(["lever", "dial", "button", "throttle"]),
-      z.literal("GROUP SELECTED")
-    ], {
+    // Any id from the workspace switch type registry, or "GROUP SELECTED"
+    switchType: z.string({
       required_error: "switch type is required",
       invalid_type_error: "switch type is required",
-    }),
+    }).min(1, "switch type is required"),
     switchName: z.string().min(1, "switch name is required"),
     switchDescription: z.string().min(5, "switch description must be at least 5 characters long"),
     movementAxis: z.enum(["X", "Y", "Z"], {
       required_error: "movement axis is required",
       invalid_type_error: "movement axis is required",
     }),
-    movementMode: z.boolean(),
+    motion: z.enum(["rotate", "translate"]),
+    units: z.enum(["degrees", "radians", "cm", "m"]),
+    discrete: z.boolean(),
     momentarySwitch: z.boolean(),
     bleedMargins: z.number({
       required_error: "bleed margins are required",
@@ -30,7 +30,19 @@ export const formSchema = z
       invalid_type_error: "lower limit must be a number",
     }),
     rawNodeName: z.string().optional(), // Added raw node name field (optional to maintain backward compatibility)
+    visualNodeName: z.string().nullable().optional(),
+    colliderNodeName: z.string().nullable().optional(),
   })
+  .refine(
+    (data) =>
+      data.motion === "rotate"
+        ? data.units === "degrees" || data.units === "radians"
+        : data.units === "cm" || data.units === "m",
+    {
+      message: "units must match the motion",
+      path: ["units"],
+    },
+  )
   .refine(
     (data) => {
       if (data.momentarySwitch) {
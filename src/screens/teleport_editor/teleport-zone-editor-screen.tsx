@@ -41,9 +41,9 @@ function TeleportEditorContent() {
       .sort((a, b) => a.localeCompare(b))
       .map((key) => ({
         name: key,
-        x: zones[key].x,
-        y: zones[key].y,
-        z: zones[key].z,
+        x: zones[key].position.x,
+        y: zones[key].position.y,
+        z: zones[key].position.z,
       }));
   }, [planeData]);
 
@@ -5,4 +5,6 @@ export interface SwitchItem {
   mesh: Mesh;
   isConfigured: boolean;
   switchType: string;
+  visualNodeName: string | null;
+  colliderNodeName: string | null;
 }
@@ -1,3 +1,7 @@
+// Hand-written reader kept for reference. The generate_unreal_code command writes
+// PlaneConfigTypes.h and PlaneConfigDeserializer.cpp, which replace it. When switching over,
+// make file_handling.h #include "PlaneConfigTypes.h" and drop its own struct and enum definitions.
+
 #include "file_handling.h"
 #include "Misc/FileHelper.h"
 #include "Misc/Paths.h"
@@ -36,11 +40,9 @@ void Ufile_handling::DeserializePlaneConfig(const FString &FilePath, FString &Pl
     }
 
     const TSharedPtr<FJsonObject> *TeleportZonesObject = nullptr;
-    if (RootObject->TryGetObjectField(TEXT("TeleportZones"), TeleportZonesObject))
+    if (RootObject->TryGetObjectField(TEXT("teleportZones"), TeleportZonesObject))
     {
-        const TSharedPtr<FJsonObject> TeleportZonesJson = RootObject->GetObjectField(TEXT("TeleportZones"));
-
-        for (const auto &TeleportZonePair : TeleportZonesJson->Values)
+        for (const auto &TeleportZonePair : (*TeleportZonesObject)->Values)
         {
             TSharedPtr<FJsonObject> TeleportZoneJson = TeleportZonePair.Value->AsObject();
             if (!TeleportZoneJson.IsValid())
@@ -54,11 +56,11 @@ void Ufile_handling::DeserializePlaneConfig(const FString &FilePath, FString &Pl
             {
                 const TSharedPtr<FJsonObject> DimensionsJson = TeleportZoneJson->GetObjectField(TEXT("dimensions"));
 
-                if (DimensionsJson->HasField(TEXT("X")) && DimensionsJson->HasField(TEXT("Y")) && DimensionsJson->HasField(TEXT("Z")))
+                if (DimensionsJson->HasField(TEXT("x")) && DimensionsJson->HasField(TEXT("y")) && DimensionsJson->HasField(TEXT("z")))
                 {
-                    NewTeleportZone.Dimension.X = DimensionsJson->GetNumberField(TEXT("X"));
-                    NewTeleportZone.Dimension.Y = DimensionsJson->GetNumberField(TEXT("Y"));
-                    NewTeleportZone.Dimension.Z = DimensionsJson->GetNumberField(TEXT("Z"));
+                    NewTeleportZone.Dimension.X = DimensionsJson->GetNumberField(TEXT("x"));
+                    NewTeleportZone.Dimension.Y = DimensionsJson->GetNumberField(TEXT("y"));
+                    NewTeleportZone.Dimension.Z = DimensionsJson->GetNumberField(TEXT("z"));
                 }
             }
 
@@ -66,11 +68,11 @@ void Ufile_handling::DeserializePlaneConfig(const FString &FilePath, FString &Pl
             {
                 const TSharedPtr<FJsonObject> PositionJson = TeleportZoneJson->GetObjectField(TEXT("position"));
 
-                if (PositionJson->HasField(TEXT("X")) && PositionJson->HasField(TEXT("Y")) && PositionJson->HasField(TEXT("Z")))
+                if (PositionJson->HasField(TEXT("x")) && PositionJson->HasField(TEXT("y")) && PositionJson->HasField(TEXT("z")))
                 {
-                    NewTeleportZone.Position.X = PositionJson->GetNumberField(TEXT("X"));
-                    NewTeleportZone.Position.Y = PositionJson->GetNumberField(TEXT("Y"));
-                    NewTeleportZone.Position.Z = PositionJson->GetNumberField(TEXT("Z"));
+                    NewTeleportZone.Position.X = PositionJson->GetNumberField(TEXT("x"));
+                    NewTeleportZone.Position.Y = PositionJson->GetNumberField(TEXT("y"));
+                    NewTeleportZone.Position.Z = PositionJson->GetNumberField(TEXT("z"));
                 }
             }
 
@@ -113,9 +115,21 @@ void Ufile_handling::DeserializePlaneConfig(const FString &FilePath, FString &Pl
             {
                 NewSwitch.RawNodeName = SwitchJson->GetStringField(TEXT("rawNodeName"));
             }
-            if (SwitchJson->HasTypedField<EJson::Boolean>(TEXT("movementMode")))
+            if (SwitchJson->HasTypedField<EJson::String>(TEXT("visualNodeName")))
+            {
+                NewSwitch.VisualNodeName = SwitchJson->GetStringField(TEXT("visualNodeName"));
+            }
+            if (SwitchJson->HasTypedField<EJson::String>(TEXT("colliderNodeName")))
+            {
+                NewSwitch.ColliderNodeName = SwitchJson->GetStringField(TEXT("colliderNodeName"));
+            }
+            if (SwitchJson->HasTypedField<EJson::String>(TEXT("motion")))
+            {
+                NewSwitch.Motion = SwitchJson->GetStringField(TEXT("motion"));
+            }
+            if (SwitchJson->HasTypedField<EJson::String>(TEXT("units")))
             {
-                NewSwitch.bMovementMode = SwitchJson->GetBoolField(TEXT("movementMode"));
+                NewSwitch.Units = SwitchJson->GetStringField(TEXT("units"));
             }
             if (SwitchJson->HasTypedField<EJson::Boolean>(TEXT("momentarySwitch")))
             {